@@ -5,6 +5,7 @@ use std::time::Duration;
 use ferret_rs::system::TimerTrait;
 use sdl2::render::Texture;
 use ferret_rs::system::ScreenTrait;
+use ferret_rs::system::FrameBuffer;
 use sdl2::render::Canvas;
 use sdl2::EventPump;
 use sdl2::event::Event;
@@ -223,6 +224,16 @@ impl<'a> ScreenTrait for SDL2Screen<'a> {
         self.color_buffer[index * 4 + 3] = 255;
     }
 
+    fn get_pixel(&self, x: u16, y: u16) -> (u8, u8, u8) {
+        let index = (y * self.get_width() + x) as usize;
+
+        (
+            self.color_buffer[index * 4 + 0],
+            self.color_buffer[index * 4 + 1],
+            self.color_buffer[index * 4 + 2]
+        )
+    }
+
     fn clear(&mut self, r: u8, g: u8, b: u8) {
         for index in (0..self.color_buffer.len()).step_by(4) {
             self.color_buffer[index + 0] = r;
@@ -244,6 +255,13 @@ impl<'a> ScreenTrait for SDL2Screen<'a> {
 
         Ok(())
     }
+
+    fn get_frame_buffer(&mut self) -> Option<FrameBuffer<'_>> {
+        Some(FrameBuffer {
+            pixels: self.color_buffer,
+            bytes_per_pixel: 4
+        })
+    }
 }
 
 pub struct SDL2Timer {
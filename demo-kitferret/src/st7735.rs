@@ -2,6 +2,7 @@ pub mod instruction;
 
 use crate::spi_controller::SpiController;
 use ferret_rs::system::ScreenTrait;
+use ferret_graphics::interlace_field::InterlaceField;
 use crate::st7735::instruction::Instruction;
 
 use embedded_hal::blocking::delay::DelayMs;
@@ -254,6 +255,24 @@ where
         }
     }
 
+    fn get_pixel_internal(&self, x: u16, y: u16) -> (u8, u8, u8) {
+        if x >= self.width || y >= self.height {
+            return (0, 0, 0);
+        }
+
+        if let Some(buffer) = &self.buffer {
+            let index = (y * self.width + x) as usize;
+            let color = u16::from_be_bytes([buffer[index * 2 + 0], buffer[index * 2 + 1]]);
+
+            let r = ((color >> 11) & 0b0001_1111) * 255 / 31;
+            let g = ((color >> 5) & 0b0011_1111) * 255 / 63;
+            let b = (color & 0b0001_1111) * 255 / 31;
+
+            (r as u8, g as u8, b as u8)
+        } else {
+            (0, 0, 0)
+        }
+    }
 
     fn clear_internal(&mut self, red: u8, green: u8, blue: u8) {
         if let Some(buffer) = &mut self.buffer {
@@ -284,6 +303,10 @@ where
         self.set_pixel_internal(x, y, r, g, b);
     }
 
+    fn get_pixel(&self, x: u16, y: u16) -> (u8, u8, u8) {
+        self.get_pixel_internal(x, y)
+    }
+
     fn clear(&mut self, r: u8, g: u8, b: u8) {
         self.clear_internal(r, g, b);
     }
@@ -295,4 +318,14 @@ where
             self.update_entire_screen()
         }
     }
+
+    fn get_interlace_field(&self) -> Option<InterlaceField> {
+        if !self.interlace {
+            None
+        } else if self.interlace_even {
+            Some(InterlaceField::Even)
+        } else {
+            Some(InterlaceField::Odd)
+        }
+    }
 }
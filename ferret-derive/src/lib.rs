@@ -0,0 +1,129 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::parse_macro_input;
+use syn::spanned::Spanned;
+use syn::Data;
+use syn::DeriveInput;
+use syn::Fields;
+
+/// Derives `Varyings` for a struct with named fields, mapping each field to
+/// consecutive interpolated slots in declaration order. The field tagged
+/// `#[position]` is moved to slots 0..3 and must hold four components.
+#[proc_macro_derive(Varyings, attributes(position))]
+pub fn derive_varyings(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match expand_varyings(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into()
+    }
+}
+
+fn expand_varyings(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            input.generics.span(),
+            "Varyings can't be derived for generic structs"));
+    }
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(syn::Error::new(
+                input.ident.span(),
+                "Varyings can only be derived for structs with named fields"))
+        },
+        _ => return Err(syn::Error::new(
+            input.ident.span(),
+            "Varyings can only be derived for structs"))
+    };
+
+    let mut position = None;
+    let mut others = Vec::new();
+
+    for field in fields {
+        let is_position = field.attrs.iter().any(|attr| attr.path.is_ident("position"));
+
+        if is_position {
+            if position.is_some() {
+                return Err(syn::Error::new(
+                    field.span(),
+                    "only one field can be tagged #[position]"));
+            }
+
+            position = Some(field);
+        } else {
+            others.push(field);
+        }
+    }
+
+    let ordered: Vec<_> = position.iter().copied().chain(others.iter().copied()).collect();
+
+    let ident = &input.ident;
+    let root = quote!(::ferret_graphics::ferret_3d::varyings);
+
+    let sizes: Vec<_> = ordered.iter()
+        .map(|field| {
+            let ty = &field.ty;
+            quote!(<#ty as #root::VaryingField>::SIZE)
+        })
+        .collect();
+
+    let size = quote!({ 0 #(+ #sizes)* });
+
+    let writes = ordered.iter().zip(sizes.iter()).map(|(field, field_size)| {
+        let name = &field.ident;
+        quote! {
+            #root::VaryingField::write_slots(&self.#name, &mut slots[offset..offset + #field_size]);
+            offset += #field_size;
+        }
+    });
+
+    let reads = ordered.iter().zip(sizes.iter()).map(|(field, field_size)| {
+        let name = &field.ident;
+        let ty = &field.ty;
+        quote! {
+            let #name = <#ty as #root::VaryingField>::read_slots(&slots[offset..offset + #field_size]);
+            offset += #field_size;
+        }
+    });
+
+    let names = ordered.iter().map(|field| &field.ident);
+
+    let position_impl = match position {
+        Some(field) => {
+            let ty = &field.ty;
+            quote! {
+                const _: () = assert!(
+                    <#ty as #root::VaryingField>::SIZE == 4,
+                    "the #[position] field must have four components (clip-space xyzw)");
+
+                impl #root::ClipPosition for #ident {}
+            }
+        },
+        None => quote!()
+    };
+
+    Ok(quote! {
+        impl #root::Varyings<#size> for #ident {
+            #[allow(unused_assignments)]
+            fn write_slots(&self, slots: &mut [f32]) {
+                let mut offset = 0usize;
+                #(#writes)*
+            }
+
+            #[allow(unused_assignments)]
+            fn read_slots(slots: &[f32]) -> Self {
+                let mut offset = 0usize;
+                #(#reads)*
+
+                Self {
+                    #(#names),*
+                }
+            }
+        }
+
+        #position_impl
+    })
+}
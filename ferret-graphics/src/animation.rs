@@ -0,0 +1,172 @@
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum AnimationMode {
+    Loop,
+    PingPong,
+    Once
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct AnimationFrame {
+    /// Index of the frame in the sprite sheet.
+    pub sprite: usize,
+    pub duration_millis: u32,
+
+    /// User value reported when the animation enters this frame.
+    pub event: Option<u16>
+}
+
+impl AnimationFrame {
+    pub fn new(sprite: usize, duration_millis: u32) -> Self {
+        Self {
+            sprite,
+            duration_millis,
+            event: None
+        }
+    }
+}
+
+/// Plays a sequence of sprite sheet frames, advanced by the elapsed time of
+/// each game loop iteration.
+pub struct Animation<'f> {
+    frames: &'f [AnimationFrame],
+    mode: AnimationMode,
+    current: usize,
+    elapsed_millis: u32,
+    forward: bool,
+    finished: bool
+}
+
+impl<'f> Animation<'f> {
+    pub fn new(frames: &'f [AnimationFrame], mode: AnimationMode) -> Self {
+        Self {
+            frames,
+            mode,
+            current: 0,
+            elapsed_millis: 0,
+            forward: true,
+            finished: false
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.current = 0;
+        self.elapsed_millis = 0;
+        self.forward = true;
+        self.finished = false;
+    }
+
+    /// Advances the animation by `delta_millis`, as measured by the timer.
+    /// `on_event` is called with the event of every frame entered, in order,
+    /// so none are lost when several frames pass in one update.
+    pub fn update<F: FnMut(u16)>(&mut self, delta_millis: u32, mut on_event: F) {
+        if self.frames.is_empty() || self.finished {
+            return;
+        }
+
+        self.elapsed_millis += delta_millis;
+
+        loop {
+            let duration = self.frames[self.current].duration_millis.max(1);
+
+            if self.elapsed_millis < duration {
+                break;
+            }
+
+            match self.next_index() {
+                Some(next) => {
+                    self.elapsed_millis -= duration;
+                    self.current = next;
+
+                    if let Some(event) = self.frames[next].event {
+                        on_event(event);
+                    }
+                },
+                None => {
+                    self.elapsed_millis = 0;
+                    self.finished = true;
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Sprite sheet frame to draw, or None for an animation with no frames.
+    pub fn sprite(&self) -> Option<usize> {
+        self.frames.get(self.current).map(|frame| frame.sprite)
+    }
+
+    /// Whether a `Once` animation has played its last frame to the end.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    fn next_index(&mut self) -> Option<usize> {
+        let last = self.frames.len() - 1;
+
+        match self.mode {
+            AnimationMode::Loop => Some(if self.current == last { 0 } else { self.current + 1 }),
+            AnimationMode::Once => if self.current == last { None } else { Some(self.current + 1) },
+            AnimationMode::PingPong => {
+                if last == 0 {
+                    return Some(0);
+                }
+
+                if self.forward && self.current == last {
+                    self.forward = false;
+                } else if !self.forward && self.current == 0 {
+                    self.forward = true;
+                }
+
+                Some(if self.forward { self.current + 1 } else { self.current - 1 })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn frames() -> [AnimationFrame; 3] {
+        [
+            AnimationFrame::new(4, 100),
+            AnimationFrame { sprite: 5, duration_millis: 50, event: Some(7) },
+            AnimationFrame::new(6, 100)
+        ]
+    }
+
+    #[test]
+    fn ping_pong_reverses_and_reports_events() {
+        let frames = frames();
+        let mut animation = Animation::new(&frames, AnimationMode::PingPong);
+        let mut events = 0;
+
+        let mut sprites = [0; 6];
+        for sprite in sprites.iter_mut() {
+            *sprite = animation.sprite().unwrap();
+            animation.update(100, |event| {
+                assert_eq!(event, 7);
+                events += 1;
+            });
+        }
+
+        // Frame 5 is passed within a single update on the way back, but its
+        // event is still reported
+        assert_eq!(sprites, [4, 5, 6, 4, 5, 6]);
+        assert_eq!(events, 4);
+    }
+
+    #[test]
+    fn once_stops_on_last_frame() {
+        let frames = frames();
+        let mut animation = Animation::new(&frames, AnimationMode::Once);
+
+        animation.update(249, |_| ());
+        assert_eq!(animation.sprite(), Some(6));
+        assert!(!animation.is_finished());
+
+        animation.update(1000, |_| ());
+        assert_eq!(animation.sprite(), Some(6));
+        assert!(animation.is_finished());
+    }
+}
@@ -0,0 +1,674 @@
+use crate::FerretGraphics;
+use crate::Rect;
+use crate::color::Color;
+use crate::ferret_3d::texture::Texture;
+use crate::perspective_floor::FloorCamera;
+use crate::perspective_floor::FloorSource;
+use crate::screen_trait::ScreenTrait;
+use crate::sprite_options::SpriteOptions;
+use crate::sprite_sheet::SpriteSheet;
+use crate::sprite_transform::SpriteTransform;
+use crate::tilemap::Tilemap;
+
+use nalgebra::Rotation2;
+use nalgebra::Vector2;
+
+/// Most edge crossings `fill_polygon` handles on a single row.
+const MAX_POLYGON_CROSSINGS: usize = 32;
+
+fn floor_div(value: i32, divisor: i32) -> i32 {
+    let quotient = value / divisor;
+
+    if value % divisor < 0 {
+        quotient - 1
+    } else {
+        quotient
+    }
+}
+
+fn floor_i32(value: f32) -> i32 {
+    -ceil_i32(-value)
+}
+
+pub fn ceil_i32(value: f32) -> i32 {
+    let truncated = value as i32;
+
+    if (truncated as f32) < value {
+        truncated + 1
+    } else {
+        truncated
+    }
+}
+
+fn round_i64(value: f64) -> i64 {
+    let shifted = value + 0.5;
+    let truncated = shifted as i64;
+
+    if (truncated as f64) > shifted {
+        truncated - 1
+    } else {
+        truncated
+    }
+}
+
+/// Liang–Barsky clip of the segment from `from` to `to` against the
+/// rectangle `min` to `max`, both inclusive. Ends that move are rounded to
+/// the nearest pixel, None when the segment misses the rectangle.
+fn clip_line(from: (i64, i64), to: (i64, i64), min: (i64, i64), max: (i64, i64)) -> Option<((i64, i64), (i64, i64))> {
+    let dx = (to.0 - from.0) as f64;
+    let dy = (to.1 - from.1) as f64;
+
+    let mut t0 = 0.0f64;
+    let mut t1 = 1.0f64;
+
+    let boundaries = [
+        (-dx, (from.0 - min.0) as f64),
+        (dx, (max.0 - from.0) as f64),
+        (-dy, (from.1 - min.1) as f64),
+        (dy, (max.1 - from.1) as f64)
+    ];
+
+    for (p, q) in boundaries {
+        if p == 0.0 {
+            if q < 0.0 {
+                return None;
+            }
+        } else if p < 0.0 {
+            t0 = t0.max(q / p);
+        } else {
+            t1 = t1.min(q / p);
+        }
+    }
+
+    if t0 > t1 {
+        return None;
+    }
+
+    let point_at = |t: f64, end: (i64, i64)| {
+        if t == 0.0 {
+            return from;
+        }
+
+        if t == 1.0 {
+            return end;
+        }
+
+        (
+            round_i64(from.0 as f64 + t * dx).clamp(min.0, max.0),
+            round_i64(from.1 as f64 + t * dy).clamp(min.1, max.1)
+        )
+    };
+
+    Some((point_at(t0, to), point_at(t1, to)))
+}
+
+/// Calls `act` with one point per step of the first octant of a circle of
+/// radius `r`, going from (r, 0) to the diagonal.
+fn for_each_circle_point<F: FnMut(i32, i32)>(r: i32, mut act: F) {
+    let mut x = r;
+    let mut y = 0;
+    let mut err = 1 - r;
+
+    while x >= y {
+        act(x, y);
+        y += 1;
+
+        if err < 0 {
+            err += 2 * y + 1;
+        } else {
+            x -= 1;
+            err += 2 * (y - x) + 1;
+        }
+    }
+}
+
+/// Calls `act` with the points of the first quadrant of an ellipse with radii
+/// `rx` and `ry`, using the midpoint algorithm. A zero radius gives a
+/// straight line along the other axis.
+fn for_each_ellipse_point<F: FnMut(i32, i32)>(rx: i32, ry: i32, mut act: F) {
+    if ry == 0 {
+        for x in 0..=rx {
+            act(x, 0);
+        }
+        return;
+    }
+
+    if rx == 0 {
+        for y in 0..=ry {
+            act(0, y);
+        }
+        return;
+    }
+
+    let rx2 = rx as i64 * rx as i64;
+    let ry2 = ry as i64 * ry as i64;
+
+    let mut x = 0i64;
+    let mut y = ry as i64;
+    let mut dx = 0i64;
+    let mut dy = 2 * rx2 * y;
+
+    // Region where the slope is shallower than -1
+    let mut p = 4 * ry2 - 4 * rx2 * ry as i64 + rx2;
+    while dx < dy {
+        act(x as i32, y as i32);
+        x += 1;
+        dx += 2 * ry2;
+
+        if p < 0 {
+            p += 4 * (dx + ry2);
+        } else {
+            y -= 1;
+            dy -= 2 * rx2;
+            p += 4 * (dx - dy + ry2);
+        }
+    }
+
+    // Region where the slope is steeper than -1
+    let mut p = ry2 * (2 * x + 1) * (2 * x + 1) + 4 * rx2 * (y - 1) * (y - 1) - 4 * rx2 * ry2;
+    while y >= 0 {
+        act(x as i32, y as i32);
+        y -= 1;
+        dy -= 2 * rx2;
+
+        if p > 0 {
+            p += 4 * (rx2 - dy);
+        } else {
+            x += 1;
+            dx += 2 * ry2;
+            p += 4 * (dx - dy + rx2);
+        }
+    }
+}
+
+/// 2D primitives drawn straight onto the screen. Coordinates may lie outside
+/// of it, anything off-screen is clipped. Rectangles exclude their right and
+/// bottom edges.
+impl<'a, TScreen> FerretGraphics<'a, TScreen>
+where
+    TScreen: ScreenTrait
+{
+    pub fn draw_pixel(&mut self, x: i32, y: i32, color: Color) {
+        let rgb = color.as_rgb888();
+        self.plot(x, y, rgb);
+    }
+
+    /// Bresenham line from (x0, y0) to (x1, y1), both ends included. Lines
+    /// thicker than one pixel are drawn with a square brush. The line is
+    /// clipped to the screen before it is stepped.
+    pub fn draw_line(&mut self, x0: i32, y0: i32, x1: i32, y1: i32, thickness: u16, color: Color) {
+        let rgb = color.as_rgb888();
+        let thickness = thickness.max(1) as i32;
+        let offset = (thickness - 1) / 2;
+
+        // Points whose brush still touches the screen
+        let reach = (thickness - 1 - offset) as i64;
+        let min = (-reach, -reach);
+        let max = (self.screen.get_width() as i64 - 1 + offset as i64, self.screen.get_height() as i64 - 1 + offset as i64);
+
+        let ((x0, y0), (x1, y1)) = match clip_line((x0 as i64, y0 as i64), (x1 as i64, y1 as i64), min, max) {
+            Some(clipped) => clipped,
+            None => return
+        };
+
+        let dx = (x1 - x0).abs();
+        let dy = -(y1 - y0).abs();
+        let sx = if x0 < x1 { 1 } else { -1 };
+        let sy = if y0 < y1 { 1 } else { -1 };
+
+        let (mut x, mut y) = (x0, y0);
+        let mut err = dx + dy;
+
+        loop {
+            let (px, py) = (x as i32, y as i32);
+
+            if thickness == 1 {
+                self.plot(px, py, rgb);
+            } else {
+                self.fill_span_rect(px - offset, py - offset, px - offset + thickness, py - offset + thickness, rgb);
+            }
+
+            if x == x1 && y == y1 {
+                break;
+            }
+
+            let err2 = 2 * err;
+
+            if err2 >= dy {
+                err += dy;
+                x += sx;
+            }
+
+            if err2 <= dx {
+                err += dx;
+                y += sy;
+            }
+        }
+    }
+
+    pub fn draw_rect(&mut self, rect: Rect<i32>, color: Color) {
+        if rect.right <= rect.left || rect.bottom <= rect.top {
+            return;
+        }
+
+        let rgb = color.as_rgb888();
+
+        self.draw_span(rect.left, rect.right, rect.top, rgb);
+        self.draw_span(rect.left, rect.right, rect.bottom - 1, rgb);
+
+        for y in rect.top + 1..rect.bottom - 1 {
+            self.plot(rect.left, y, rgb);
+            self.plot(rect.right - 1, y, rgb);
+        }
+    }
+
+    pub fn fill_rect(&mut self, rect: Rect<i32>, color: Color) {
+        let rgb = color.as_rgb888();
+        self.fill_span_rect(rect.left, rect.top, rect.right, rect.bottom, rgb);
+    }
+
+    pub fn draw_circle(&mut self, cx: i32, cy: i32, radius: u16, color: Color) {
+        let rgb = color.as_rgb888();
+
+        for_each_circle_point(radius as i32, |x, y| {
+            self.plot(cx + x, cy + y, rgb);
+            self.plot(cx - x, cy + y, rgb);
+            self.plot(cx + x, cy - y, rgb);
+            self.plot(cx - x, cy - y, rgb);
+            self.plot(cx + y, cy + x, rgb);
+            self.plot(cx - y, cy + x, rgb);
+            self.plot(cx + y, cy - x, rgb);
+            self.plot(cx - y, cy - x, rgb);
+        });
+    }
+
+    pub fn fill_circle(&mut self, cx: i32, cy: i32, radius: u16, color: Color) {
+        let rgb = color.as_rgb888();
+
+        for_each_circle_point(radius as i32, |x, y| {
+            self.draw_span(cx - x, cx + x + 1, cy + y, rgb);
+            self.draw_span(cx - x, cx + x + 1, cy - y, rgb);
+            self.draw_span(cx - y, cx + y + 1, cy + x, rgb);
+            self.draw_span(cx - y, cx + y + 1, cy - x, rgb);
+        });
+    }
+
+    pub fn draw_ellipse(&mut self, cx: i32, cy: i32, radius_x: u16, radius_y: u16, color: Color) {
+        let rgb = color.as_rgb888();
+
+        for_each_ellipse_point(radius_x as i32, radius_y as i32, |x, y| {
+            self.plot(cx + x, cy + y, rgb);
+            self.plot(cx - x, cy + y, rgb);
+            self.plot(cx + x, cy - y, rgb);
+            self.plot(cx - x, cy - y, rgb);
+        });
+    }
+
+    pub fn fill_ellipse(&mut self, cx: i32, cy: i32, radius_x: u16, radius_y: u16, color: Color) {
+        let rgb = color.as_rgb888();
+
+        for_each_ellipse_point(radius_x as i32, radius_y as i32, |x, y| {
+            self.draw_span(cx - x, cx + x + 1, cy + y, rgb);
+            self.draw_span(cx - x, cx + x + 1, cy - y, rgb);
+        });
+    }
+
+    /// Rectangle outline whose corners are quarter circles of `radius`,
+    /// limited to half of the shorter side.
+    pub fn draw_rounded_rect(&mut self, rect: Rect<i32>, radius: u16, color: Color) {
+        let (left, top, right, bottom, r) = match Self::rounded_corners(&rect, radius) {
+            Some(corners) => corners,
+            None => return
+        };
+
+        let rgb = color.as_rgb888();
+
+        self.draw_span(left, right + 1, rect.top, rgb);
+        self.draw_span(left, right + 1, rect.bottom - 1, rgb);
+
+        for y in top..=bottom {
+            self.plot(rect.left, y, rgb);
+            self.plot(rect.right - 1, y, rgb);
+        }
+
+        for_each_circle_point(r, |x, y| {
+            for (px, py) in [(x, y), (y, x)] {
+                self.plot(right + px, bottom + py, rgb);
+                self.plot(left - px, bottom + py, rgb);
+                self.plot(right + px, top - py, rgb);
+                self.plot(left - px, top - py, rgb);
+            }
+        });
+    }
+
+    pub fn fill_rounded_rect(&mut self, rect: Rect<i32>, radius: u16, color: Color) {
+        let (left, top, right, bottom, r) = match Self::rounded_corners(&rect, radius) {
+            Some(corners) => corners,
+            None => return
+        };
+
+        let rgb = color.as_rgb888();
+
+        self.fill_span_rect(rect.left, top, rect.right, bottom + 1, rgb);
+
+        for_each_circle_point(r, |x, y| {
+            for (px, py) in [(x, y), (y, x)] {
+                self.draw_span(left - px, right + px + 1, top - py, rgb);
+                self.draw_span(left - px, right + px + 1, bottom + py, rgb);
+            }
+        });
+    }
+
+    /// Fills the polygon through `points` with the even-odd rule, sampling
+    /// pixel centres. Rows crossing more than 32 edges are partly skipped.
+    pub fn fill_polygon(&mut self, points: &[(i32, i32)], color: Color) {
+        if points.len() < 3 {
+            return;
+        }
+
+        let rgb = color.as_rgb888();
+
+        let min_y = points.iter().map(|point| point.1).min().unwrap().max(0);
+        let max_y = points.iter().map(|point| point.1).max().unwrap().min(self.screen.get_height() as i32);
+
+        for y in min_y..max_y {
+            let sample_y = y as f32 + 0.5;
+            let mut crossings = [0.0f32; MAX_POLYGON_CROSSINGS];
+            let mut count = 0;
+
+            for (index, &(x0, y0)) in points.iter().enumerate() {
+                let (x1, y1) = points[(index + 1) % points.len()];
+                let (fy0, fy1) = (y0 as f32, y1 as f32);
+
+                if (fy0 <= sample_y) == (fy1 <= sample_y) || count == MAX_POLYGON_CROSSINGS {
+                    continue;
+                }
+
+                let t = (sample_y - fy0) / (fy1 - fy0);
+                let x = x0 as f32 + t * (x1 - x0) as f32;
+
+                // Insertion sort, rows only cross a handful of edges
+                let mut slot = count;
+                while slot > 0 && crossings[slot - 1] > x {
+                    crossings[slot] = crossings[slot - 1];
+                    slot -= 1;
+                }
+
+                crossings[slot] = x;
+                count += 1;
+            }
+
+            for pair in crossings[..count].chunks_exact(2) {
+                self.draw_span(ceil_i32(pair[0] - 0.5), ceil_i32(pair[1] - 0.5), y, rgb);
+            }
+        }
+    }
+
+    /// Copies the `source` region of `texture` with its top left corner at
+    /// (x, y). Parts of the region outside of the texture or the screen are
+    /// skipped.
+    pub fn draw_sprite(&mut self, texture: &Texture, source: Rect<i32>, x: i32, y: i32, options: &SpriteOptions) {
+        let source = Rect::new(
+            source.left.max(0),
+            source.top.max(0),
+            source.right.min(texture.width as i32),
+            source.bottom.min(texture.height as i32));
+
+        let width = source.right - source.left;
+        let height = source.bottom - source.top;
+
+        let min_dx = (-x).max(0);
+        let min_dy = (-y).max(0);
+        let max_dx = width.min(self.screen.get_width() as i32 - x);
+        let max_dy = height.min(self.screen.get_height() as i32 - y);
+
+        for dy in min_dy..max_dy {
+            for dx in min_dx..max_dx {
+                let rgb = Self::sprite_texel(texture, &source, dx, dy, options);
+                self.put_sprite_pixel((x + dx) as u16, (y + dy) as u16, rgb, options);
+            }
+        }
+    }
+
+    /// Draws the `source` region of `texture` rotated and scaled around the
+    /// transform's pivot, which lands on (x, y). Each covered screen pixel is
+    /// mapped back into the sprite, like the affine sprites of the GBA.
+    pub fn draw_sprite_transformed(
+        &mut self,
+        texture: &Texture,
+        source: Rect<i32>,
+        x: i32,
+        y: i32,
+        transform: &SpriteTransform,
+        options: &SpriteOptions
+    ) {
+        let source = Rect::new(
+            source.left.max(0),
+            source.top.max(0),
+            source.right.min(texture.width as i32),
+            source.bottom.min(texture.height as i32));
+
+        let width = (source.right - source.left) as f32;
+        let height = (source.bottom - source.top) as f32;
+
+        let matrix = transform.matrix();
+        let inverse = match matrix.try_inverse() {
+            Some(inverse) => inverse,
+            None => return
+        };
+
+        // Screen bounding box of the transformed sprite corners
+        let mut min = Vector2::new(f32::MAX, f32::MAX);
+        let mut max = Vector2::new(f32::MIN, f32::MIN);
+
+        for corner in [(0.0, 0.0), (width, 0.0), (0.0, height), (width, height)] {
+            let point = matrix * (Vector2::new(corner.0, corner.1) - transform.pivot);
+            min = min.inf(&point);
+            max = max.sup(&point);
+        }
+
+        let min_x = (x + min.x as i32 - 1).max(0);
+        let min_y = (y + min.y as i32 - 1).max(0);
+        let max_x = (x + max.x as i32 + 1).min(self.screen.get_width() as i32 - 1);
+        let max_y = (y + max.y as i32 + 1).min(self.screen.get_height() as i32 - 1);
+
+        // Change of the sprite position per screen pixel along x
+        let step_x = inverse.column(0).into_owned();
+
+        for py in min_y..=max_y {
+            let start = Vector2::new(min_x as f32 + 0.5 - x as f32, py as f32 + 0.5 - y as f32);
+            let mut texel = inverse * start + transform.pivot;
+
+            for px in min_x..=max_x {
+                if texel.x >= 0.0 && texel.y >= 0.0 && texel.x < width && texel.y < height {
+                    let rgb = Self::sprite_texel(texture, &source, texel.x as i32, texel.y as i32, options);
+                    self.put_sprite_pixel(px as u16, py as u16, rgb, options);
+                }
+
+                texel += step_x;
+            }
+        }
+    }
+
+    /// Draws frame `index` of `sheet` with its pivot at (x, y). Flipping
+    /// mirrors the sprite around the pivot.
+    pub fn draw_sprite_frame(&mut self, sheet: &SpriteSheet, index: usize, x: i32, y: i32, options: &SpriteOptions) {
+        let frame = match sheet.frame(index) {
+            Some(frame) => frame,
+            None => return
+        };
+
+        let width = frame.rect.right - frame.rect.left;
+        let height = frame.rect.bottom - frame.rect.top;
+        let pivot_x = if options.flip_x { width - 1 - frame.pivot.0 } else { frame.pivot.0 };
+        let pivot_y = if options.flip_y { height - 1 - frame.pivot.1 } else { frame.pivot.1 };
+
+        self.draw_sprite(&sheet.texture, frame.rect, x - pivot_x, y - pivot_y, options);
+    }
+
+    /// Draws every layer of `tilemap` as seen from `camera`, the world
+    /// position shown at the top left of the screen. Only tiles on screen are
+    /// visited. `time_millis` selects the frame of animated tiles.
+    pub fn draw_tilemap(&mut self, tilemap: &Tilemap, camera: (i32, i32), time_millis: u32) {
+        let tileset = &tilemap.tileset;
+        let tile_width = tileset.tile_width as i32;
+        let tile_height = tileset.tile_height as i32;
+
+        if tile_width == 0 || tile_height == 0 {
+            return;
+        }
+
+        // A texture narrower than one tile holds no tiles
+        let columns = tileset.texture.width as i32 / tile_width;
+        if columns == 0 {
+            return;
+        }
+
+        for layer in tilemap.layers {
+            let scroll_x = (camera.0 as f32 * layer.parallax.0) as i32 + layer.offset.0;
+            let scroll_y = (camera.1 as f32 * layer.parallax.1) as i32 + layer.offset.1;
+
+            let first_x = floor_div(scroll_x, tile_width).max(0);
+            let first_y = floor_div(scroll_y, tile_height).max(0);
+            let last_x = floor_div(scroll_x + self.screen.get_width() as i32 - 1, tile_width).min(layer.width as i32 - 1);
+            let last_y = floor_div(scroll_y + self.screen.get_height() as i32 - 1, tile_height).min(layer.height as i32 - 1);
+
+            for ty in first_y..=last_y {
+                for tx in first_x..=last_x {
+                    let tile = match layer.get(tx, ty) {
+                        Some(tile) => tile,
+                        None => continue
+                    };
+
+                    let index = tileset.animated_index(tile.index, time_millis) as i32;
+                    let left = index % columns * tile_width;
+                    let top = index / columns * tile_height;
+
+                    let mut options = SpriteOptions::new();
+                    options.color_key = tileset.color_key;
+                    options.flip_x = tile.flip_x;
+                    options.flip_y = tile.flip_y;
+
+                    self.draw_sprite(
+                        &tileset.texture,
+                        Rect::new(left, top, left + tile_width, top + tile_height),
+                        tx * tile_width - scroll_x,
+                        ty * tile_height - scroll_y,
+                        &options);
+                }
+            }
+        }
+    }
+
+    /// Colour at (dx, dy) inside `source`, after flipping and palette swap,
+    /// or None if it matches the colour key.
+    fn sprite_texel(texture: &Texture, source: &Rect<i32>, dx: i32, dy: i32, options: &SpriteOptions) -> Option<(u8, u8, u8)> {
+        let sx = if options.flip_x { source.right - 1 - dx } else { source.left + dx };
+        let sy = if options.flip_y { source.bottom - 1 - dy } else { source.top + dy };
+        let index = (sy as usize * texture.width as usize + sx as usize) * 3;
+        let rgb = (texture.data[index], texture.data[index + 1], texture.data[index + 2]);
+
+        if options.color_key == Some(rgb) {
+            return None;
+        }
+
+        if let Some(palette) = options.palette {
+            if let Some((_, to)) = palette.iter().find(|(from, _)| *from == rgb) {
+                return Some(*to);
+            }
+        }
+
+        Some(rgb)
+    }
+
+    fn put_sprite_pixel(&mut self, x: u16, y: u16, rgb: Option<(u8, u8, u8)>, options: &SpriteOptions) {
+        let mut rgb = match rgb {
+            Some(rgb) => rgb,
+            None => return
+        };
+
+        if options.alpha != 255 {
+            let under = self.screen.get_pixel(x, y);
+            let blend = |over: u8, under: u8| {
+                ((over as u16 * options.alpha as u16 + under as u16 * (255 - options.alpha as u16)) / 255) as u8
+            };
+
+            rgb = (blend(rgb.0, under.0), blend(rgb.1, under.1), blend(rgb.2, under.2));
+        }
+
+        self.screen.set_pixel(x, y, rgb.0, rgb.1, rgb.2);
+    }
+
+    /// Mode-7 style ground plane filling every row below the camera horizon.
+    /// Each row lies at a single distance, so the floor is walked with one
+    /// step per pixel instead of going through `draw_mesh`.
+    pub fn draw_floor(&mut self, source: &FloorSource, camera: &FloorCamera) {
+        let width = self.screen.get_width();
+        let rotation = Rotation2::new(camera.yaw);
+        let forward = rotation * Vector2::new(1.0, 0.0);
+        let right = rotation * Vector2::new(0.0, 1.0);
+
+        if source.is_empty() {
+            return;
+        }
+
+        for y in camera.horizon.saturating_add(1)..self.screen.get_height() {
+            let distance = camera.height * camera.scale / ((y - camera.horizon) as f32 - 0.5);
+            let step = right * (distance / camera.scale);
+            let mut position = camera.position + forward * distance + step * (0.5 - width as f32 / 2.0);
+
+            for x in 0..width {
+                if let Some(rgb) = source.sample(floor_i32(position.x), floor_i32(position.y)) {
+                    self.screen.set_pixel(x, y, rgb.0, rgb.1, rgb.2);
+                }
+
+                position += step;
+            }
+        }
+    }
+
+    fn rounded_corners(rect: &Rect<i32>, radius: u16) -> Option<(i32, i32, i32, i32, i32)> {
+        let width = rect.right - rect.left;
+        let height = rect.bottom - rect.top;
+
+        if width <= 0 || height <= 0 {
+            return None;
+        }
+
+        let r = (radius as i32).min((width - 1) / 2).min((height - 1) / 2);
+
+        // Centres of the corner circles
+        Some((rect.left + r, rect.top + r, rect.right - 1 - r, rect.bottom - 1 - r, r))
+    }
+
+    fn plot(&mut self, x: i32, y: i32, rgb: (u8, u8, u8)) {
+        if x < 0 || y < 0 || x >= self.screen.get_width() as i32 || y >= self.screen.get_height() as i32 {
+            return;
+        }
+
+        self.screen.set_pixel(x as u16, y as u16, rgb.0, rgb.1, rgb.2);
+    }
+
+    /// Horizontal run of pixels from `x0` up to, not including, `x1`.
+    fn draw_span(&mut self, x0: i32, x1: i32, y: i32, rgb: (u8, u8, u8)) {
+        if y < 0 || y >= self.screen.get_height() as i32 {
+            return;
+        }
+
+        let x0 = x0.max(0);
+        let x1 = x1.min(self.screen.get_width() as i32);
+
+        for x in x0..x1 {
+            self.screen.set_pixel(x as u16, y as u16, rgb.0, rgb.1, rgb.2);
+        }
+    }
+
+    fn fill_span_rect(&mut self, left: i32, top: i32, right: i32, bottom: i32, rgb: (u8, u8, u8)) {
+        let top = top.max(0);
+        let bottom = bottom.min(self.screen.get_height() as i32);
+
+        for y in top..bottom {
+            self.draw_span(left, right, y, rgb);
+        }
+    }
+}
@@ -1,8 +1,28 @@
+pub mod blend_mode;
+pub mod cull_mode;
+pub mod draw_mesh_error;
 pub mod edge_data;
 pub mod edge_equation;
+pub mod instance_data;
+pub mod instanced_vertex_shader_trait;
+pub mod material;
+pub mod model;
+pub mod ordering_table;
+#[cfg(feature = "std")]
+pub mod parallel_queue;
 pub mod parameter_equation;
+pub mod pixel_context;
 pub mod pixel_data;
+pub mod pixel_row;
 pub mod pixel_shader_trait;
+pub mod rasterizer;
+pub mod render_scale;
+pub mod scan_edge;
+pub mod texture;
+pub mod transparent_queue;
 pub mod triangle_edge_test;
 pub mod triangle_equations;
+pub mod triangle_shading;
+pub mod typed_shader;
+pub mod varyings;
 pub mod vertex_shader_trait;
\ No newline at end of file
@@ -0,0 +1,26 @@
+/// How a shaded pixel is combined with the colour already in the target.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum BlendMode {
+    Opaque,
+
+    /// Mixes the shaded colour over the target with a constant opacity, 255
+    /// being opaque.
+    Alpha(u8),
+
+    /// Adds the shaded colour to the target, saturating at white.
+    Additive
+}
+
+impl BlendMode {
+    pub fn blend(&self, over: (u8, u8, u8), under: (u8, u8, u8)) -> (u8, u8, u8) {
+        let channel = |over: u8, under: u8| match self {
+            BlendMode::Opaque => over,
+            BlendMode::Alpha(alpha) => {
+                ((over as u16 * *alpha as u16 + under as u16 * (255 - *alpha as u16)) / 255) as u8
+            },
+            BlendMode::Additive => over.saturating_add(under)
+        };
+
+        (channel(over.0, under.0), channel(over.1, under.1), channel(over.2, under.2))
+    }
+}
@@ -0,0 +1,8 @@
+/// Which triangles `draw_triangle` skips, by their winding on screen.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum CullMode {
+    /// Both sides are drawn, `PixelContext::front_facing` tells them apart.
+    None,
+    Front,
+    Back
+}
@@ -0,0 +1,21 @@
+use core::fmt;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum DrawMeshError {
+    /// `index` in triangle number `triangle` is past the end of the vertices.
+    IndexOutOfRange { triangle: usize, index: usize },
+    /// Vertex `index` has a NaN or infinite component, either as input or
+    /// after the vertex shader, or a clip-space w of zero.
+    NonFiniteVertex { index: usize }
+}
+
+impl fmt::Display for DrawMeshError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DrawMeshError::IndexOutOfRange { triangle, index } =>
+                write!(f, "triangle {} references vertex {} which is out of range", triangle, index),
+            DrawMeshError::NonFiniteVertex { index } =>
+                write!(f, "vertex {} is not finite", index)
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use crate::color::Color;
+
+use nalgebra::Matrix4;
+use nalgebra::Vector2;
+
+/// Per-instance values handed to an instanced vertex shader together with
+/// each shared vertex.
+#[derive(Copy, Clone)]
+pub struct InstanceData {
+    pub matrix: Matrix4<f32>,
+    pub tint: Color,
+    pub uv_offset: Vector2<f32>
+}
+
+impl InstanceData {
+    pub fn new(matrix: Matrix4<f32>) -> Self {
+        Self {
+            matrix,
+            tint: Color(1.0, 1.0, 1.0),
+            uv_offset: Vector2::zeros()
+        }
+    }
+}
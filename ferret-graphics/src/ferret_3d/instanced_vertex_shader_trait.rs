@@ -0,0 +1,7 @@
+use crate::ferret_3d::instance_data::InstanceData;
+
+use nalgebra::SVector;
+
+pub trait InstancedVertexShaderTrait<const TVERTEX_IN_SIZE: usize, const TVERTEX_OUT_SIZE: usize> {
+    fn process(&self, instance: &InstanceData, v: &SVector<f32, TVERTEX_IN_SIZE>) -> SVector<f32, TVERTEX_OUT_SIZE>;
+}
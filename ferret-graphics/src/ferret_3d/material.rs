@@ -0,0 +1,68 @@
+use crate::color::Color;
+use crate::ferret_3d::blend_mode::BlendMode;
+use crate::ferret_3d::cull_mode::CullMode;
+use crate::ferret_3d::pixel_context::PixelContext;
+use crate::ferret_3d::pixel_data::PixelData;
+use crate::ferret_3d::pixel_shader_trait::PixelShaderTrait;
+use crate::ferret_3d::texture::Texture;
+use crate::ferret_3d::vertex_shader_trait::VertexShaderTrait;
+
+use nalgebra::Vector3;
+
+/// Values a material hands to its pixel shader through `PixelContext`.
+#[derive(Copy, Clone)]
+pub struct MaterialProperties<'m> {
+    pub color: Color,
+    pub texture: Option<Texture<'m>>
+}
+
+/// Shader pair and render state used for one part of a `Model`. Shaders are
+/// trait objects, so materials can be picked at runtime. Materials that
+/// blend are drawn after the opaque ones and never write depth.
+#[derive(Copy, Clone)]
+pub struct Material<'m, const TVECTOR_IN_SIZE: usize, const TVECTOR_OUT_SIZE: usize> {
+    pub vertex_shader: &'m dyn VertexShaderTrait<TVECTOR_IN_SIZE, TVECTOR_OUT_SIZE>,
+    pub pixel_shader: &'m dyn PixelShaderTrait<TVECTOR_OUT_SIZE>,
+    pub properties: MaterialProperties<'m>,
+    pub cull_mode: CullMode,
+    pub depth_test: bool,
+    pub depth_write: bool,
+    pub blend_mode: BlendMode
+}
+
+impl<'m, const TVECTOR_IN_SIZE: usize, const TVECTOR_OUT_SIZE: usize> Material<'m, TVECTOR_IN_SIZE, TVECTOR_OUT_SIZE> {
+    pub fn new(
+        vertex_shader: &'m dyn VertexShaderTrait<TVECTOR_IN_SIZE, TVECTOR_OUT_SIZE>,
+        pixel_shader: &'m dyn PixelShaderTrait<TVECTOR_OUT_SIZE>
+    ) -> Self {
+        Self {
+            vertex_shader,
+            pixel_shader,
+            properties: MaterialProperties {
+                color: Color(1.0, 1.0, 1.0),
+                texture: None
+            },
+            cull_mode: CullMode::Back,
+            depth_test: true,
+            depth_write: true,
+            blend_mode: BlendMode::Opaque
+        }
+    }
+}
+
+/// Runs a material's pixel shader with its properties in the context.
+pub struct MaterialPixelShader<'m, const TVECTOR_SIZE: usize> {
+    pub pixel_shader: &'m dyn PixelShaderTrait<TVECTOR_SIZE>,
+    pub properties: &'m MaterialProperties<'m>
+}
+
+impl<'m, const TVECTOR_SIZE: usize> PixelShaderTrait<TVECTOR_SIZE> for MaterialPixelShader<'m, TVECTOR_SIZE> {
+    fn process(&self, pixel_data: &PixelData<TVECTOR_SIZE>, context: &PixelContext) -> Vector3<f32> {
+        let context = PixelContext {
+            material: Some(self.properties),
+            ..*context
+        };
+
+        self.pixel_shader.process(pixel_data, &context)
+    }
+}
@@ -0,0 +1,17 @@
+use nalgebra::SVector;
+
+/// Run of `count` triangles starting at `start` in a model's index list,
+/// drawn with `materials[material]`.
+#[derive(Copy, Clone)]
+pub struct SubMesh {
+    pub start: usize,
+    pub count: usize,
+    pub material: usize
+}
+
+/// Mesh split into sub-meshes that each use their own material.
+pub struct Model<'m, const TVECTOR_IN_SIZE: usize> {
+    pub vertices: &'m [SVector<f32, TVECTOR_IN_SIZE>],
+    pub indexes: &'m [(usize, usize, usize)],
+    pub sub_meshes: &'m [SubMesh]
+}
@@ -0,0 +1,207 @@
+use crate::ferret_3d::pixel_shader_trait::PixelShaderTrait;
+
+use nalgebra::SVector;
+
+#[derive(Copy, Clone)]
+pub struct OrderingTableEntry<'s, const TVECTOR_SIZE: usize> {
+    pub v0: SVector<f32, TVECTOR_SIZE>,
+    pub v1: SVector<f32, TVECTOR_SIZE>,
+    pub v2: SVector<f32, TVECTOR_SIZE>,
+    pub pixel_shader: &'s dyn PixelShaderTrait<TVECTOR_SIZE>,
+    next: Option<usize>
+}
+
+/// PS1-style ordering table: triangles are linked into buckets by their
+/// average view depth and drawn back-to-front on flush, so no depth buffer
+/// is needed. Storage is provided by the caller, one bucket head per depth
+/// slot and one entry per triangle that can be queued in a frame.
+pub struct OrderingTable<'a, 's, const TVECTOR_SIZE: usize> {
+    buckets: &'a mut [Option<usize>],
+    entries: &'a mut [Option<OrderingTableEntry<'s, TVECTOR_SIZE>>],
+    count: usize,
+    max_depth: f32
+}
+
+impl<'a, 's, const TVECTOR_SIZE: usize> OrderingTable<'a, 's, TVECTOR_SIZE> {
+    pub fn new(
+        buckets: &'a mut [Option<usize>],
+        entries: &'a mut [Option<OrderingTableEntry<'s, TVECTOR_SIZE>>],
+        max_depth: f32
+    ) -> Self {
+        let mut table = Self {
+            buckets,
+            entries,
+            count: 0,
+            max_depth
+        };
+
+        table.clear();
+        table
+    }
+
+    pub fn clear(&mut self) {
+        for bucket in self.buckets.iter_mut() {
+            *bucket = None;
+        }
+
+        for entry in self.entries[..self.count].iter_mut() {
+            *entry = None;
+        }
+
+        self.count = 0;
+    }
+
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.count >= self.entries.len()
+    }
+
+    /// Queues a triangle in the bucket matching `depth` (view-space w).
+    /// Returns false and drops the triangle once the table is full.
+    pub fn insert(
+        &mut self,
+        depth: f32,
+        v0: SVector<f32, TVECTOR_SIZE>,
+        v1: SVector<f32, TVECTOR_SIZE>,
+        v2: SVector<f32, TVECTOR_SIZE>,
+        pixel_shader: &'s dyn PixelShaderTrait<TVECTOR_SIZE>
+    ) -> bool {
+        if self.is_full() || self.buckets.is_empty() {
+            return false;
+        }
+
+        let bucket = self.bucket_index(depth);
+
+        self.entries[self.count] = Some(OrderingTableEntry {
+            v0, v1, v2,
+            pixel_shader,
+            next: self.buckets[bucket]
+        });
+
+        self.buckets[bucket] = Some(self.count);
+        self.count += 1;
+
+        true
+    }
+
+    /// Visits queued triangles from the farthest bucket to the nearest.
+    /// Within a bucket the last queued triangle comes first.
+    pub fn for_each_back_to_front<F>(&self, mut act: F)
+    where
+        F: FnMut(&OrderingTableEntry<'s, TVECTOR_SIZE>)
+    {
+        for bucket in self.buckets.iter().rev() {
+            let mut current = *bucket;
+
+            while let Some(index) = current {
+                let entry = self.entries[index].as_ref().unwrap();
+                act(entry);
+                current = entry.next;
+            }
+        }
+    }
+
+    fn bucket_index(&self, depth: f32) -> usize {
+        let last = self.buckets.len() - 1;
+        let scaled = depth / self.max_depth * self.buckets.len() as f32;
+
+        if scaled.is_nan() || scaled <= 0.0 {
+            0
+        } else {
+            (scaled as usize).min(last)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ferret_3d::pixel_context::PixelContext;
+    use crate::ferret_3d::pixel_data::PixelData;
+    use nalgebra::Vector3;
+
+    struct NullShader;
+
+    impl PixelShaderTrait<1> for NullShader {
+        fn process(&self, _pixel_data: &PixelData<1>, _context: &PixelContext) -> Vector3<f32> {
+            Vector3::zeros()
+        }
+    }
+
+    // Tags every triangle with `id` in its first vertex
+    fn insert(table: &mut OrderingTable<'_, '_, 1>, depth: f32, id: f32) -> bool {
+        let v = SVector::<f32, 1>::new(id);
+        table.insert(depth, v, v, v, &NullShader)
+    }
+
+    fn visit_order(table: &OrderingTable<'_, '_, 1>) -> [f32; 8] {
+        let mut order = [0.0; 8];
+        let mut count = 0;
+        table.for_each_back_to_front(|entry| {
+            order[count] = entry.v0[0];
+            count += 1;
+        });
+        order
+    }
+
+    #[test]
+    fn visits_buckets_back_to_front() {
+        let mut buckets = [None; 10];
+        let mut entries = [None; 8];
+        let mut table = OrderingTable::new(&mut buckets, &mut entries, 10.0);
+
+        insert(&mut table, 1.5, 1.0);
+        insert(&mut table, 9.5, 2.0);
+        insert(&mut table, 5.5, 3.0);
+
+        // Same bucket as the first one, queued later so visited earlier
+        insert(&mut table, 1.2, 4.0);
+
+        assert_eq!(visit_order(&table)[..4], [2.0, 3.0, 4.0, 1.0]);
+    }
+
+    #[test]
+    fn clamps_out_of_range_depth() {
+        let mut buckets = [None; 4];
+        let mut entries = [None; 8];
+        let mut table = OrderingTable::new(&mut buckets, &mut entries, 4.0);
+
+        insert(&mut table, 2.5, 1.0);
+        insert(&mut table, -3.0, 2.0);
+        insert(&mut table, f32::NAN, 3.0);
+        insert(&mut table, 100.0, 4.0);
+        insert(&mut table, 0.5, 5.0);
+
+        // Beyond max_depth lands in the farthest bucket, negative and NaN in
+        // the nearest one
+        assert_eq!(visit_order(&table)[..5], [4.0, 1.0, 5.0, 3.0, 2.0]);
+    }
+
+    #[test]
+    fn drops_triangles_when_full_and_resets_on_clear() {
+        let mut buckets = [None; 4];
+        let mut entries = [None; 2];
+        let mut table = OrderingTable::new(&mut buckets, &mut entries, 4.0);
+
+        assert!(insert(&mut table, 1.0, 1.0));
+        assert!(insert(&mut table, 2.0, 2.0));
+        assert!(table.is_full());
+        assert!(!insert(&mut table, 3.0, 3.0));
+        assert_eq!(table.len(), 2);
+        assert_eq!(visit_order(&table)[..3], [2.0, 1.0, 0.0]);
+
+        table.clear();
+        assert!(table.is_empty());
+        assert_eq!(visit_order(&table), [0.0; 8]);
+
+        assert!(insert(&mut table, 3.0, 3.0));
+        assert_eq!(visit_order(&table)[..2], [3.0, 0.0]);
+    }
+}
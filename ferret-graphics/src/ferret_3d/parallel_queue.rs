@@ -0,0 +1,89 @@
+use crate::ferret_3d::blend_mode::BlendMode;
+use crate::ferret_3d::cull_mode::CullMode;
+use crate::ferret_3d::pixel_shader_trait::PixelShaderTrait;
+use crate::ferret_3d::rasterizer::Rasterizer;
+
+use nalgebra::SVector;
+
+use std::ops::Range;
+
+pub type QueuedTriangle<const TVECTOR_SIZE: usize> = (SVector<f32, TVECTOR_SIZE>, SVector<f32, TVECTOR_SIZE>, SVector<f32, TVECTOR_SIZE>);
+
+/// Render state a mesh was queued with, applied again when it is drawn.
+#[derive(Copy, Clone)]
+pub struct QueuedRenderState {
+    pub cull_mode: CullMode,
+    pub depth_test: bool,
+    pub depth_write: bool,
+    pub depth_bias_constant: f32,
+    pub depth_bias_slope_scale: f32,
+    pub blend_mode: BlendMode,
+    pub rasterizer: Rasterizer,
+    pub object_id: u16
+}
+
+pub struct QueuedMesh<'q, 's, const TVECTOR_SIZE: usize> {
+    pub state: QueuedRenderState,
+    pub pixel_shader: &'s (dyn PixelShaderTrait<TVECTOR_SIZE> + Sync),
+    pub triangles: &'q [QueuedTriangle<TVECTOR_SIZE>]
+}
+
+/// Meshes waiting to be drawn by `flush_parallel`, already transformed to
+/// screen space. Every band of the screen draws all of them in one pass, so
+/// a frame starts its threads once however many meshes it has. The storage
+/// grows while the first frames are queued and is reused after `clear`.
+pub struct ParallelQueue<'s, const TVECTOR_SIZE: usize> {
+    triangles: Vec<QueuedTriangle<TVECTOR_SIZE>>,
+    meshes: Vec<(QueuedRenderState, &'s (dyn PixelShaderTrait<TVECTOR_SIZE> + Sync), Range<usize>)>
+}
+
+impl<'s, const TVECTOR_SIZE: usize> ParallelQueue<'s, TVECTOR_SIZE> {
+    pub fn new() -> Self {
+        Self {
+            triangles: Vec::new(),
+            meshes: Vec::new()
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.triangles.clear();
+        self.meshes.clear();
+    }
+
+    /// Number of queued triangles.
+    pub fn len(&self) -> usize {
+        self.triangles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.triangles.is_empty()
+    }
+
+    /// Starts a mesh, the following `insert_triangle` calls add to it.
+    pub fn insert_mesh(&mut self, state: QueuedRenderState, pixel_shader: &'s (dyn PixelShaderTrait<TVECTOR_SIZE> + Sync)) {
+        let start = self.triangles.len();
+        self.meshes.push((state, pixel_shader, start..start));
+    }
+
+    pub fn insert_triangle(&mut self, v0: SVector<f32, TVECTOR_SIZE>, v1: SVector<f32, TVECTOR_SIZE>, v2: SVector<f32, TVECTOR_SIZE>) {
+        if let Some(mesh) = self.meshes.last_mut() {
+            self.triangles.push((v0, v1, v2));
+            mesh.2.end = self.triangles.len();
+        }
+    }
+
+    /// Queued meshes in submission order.
+    pub fn meshes(&self) -> impl Iterator<Item = QueuedMesh<'_, 's, TVECTOR_SIZE>> {
+        self.meshes.iter().map(|(state, pixel_shader, triangles)| QueuedMesh {
+            state: *state,
+            pixel_shader: *pixel_shader,
+            triangles: &self.triangles[triangles.clone()]
+        })
+    }
+}
+
+impl<'s, const TVECTOR_SIZE: usize> Default for ParallelQueue<'s, TVECTOR_SIZE> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,22 @@
+use crate::ferret_3d::material::MaterialProperties;
+
+/// Per-pixel information passed to pixel shaders next to the varyings.
+#[derive(Copy, Clone)]
+pub struct PixelContext<'t> {
+    pub x: u16,
+    pub y: u16,
+    pub depth: f32,
+
+    /// False for back faces, which are only shaded with `CullMode::None`
+    /// or `CullMode::Front`.
+    pub front_facing: bool,
+
+    /// Screen-space derivative of each varying slot along x.
+    pub ddx: &'t [f32],
+
+    /// Screen-space derivative of each varying slot along y.
+    pub ddy: &'t [f32],
+
+    /// Properties of the material being drawn, when using `draw_model`.
+    pub material: Option<&'t MaterialProperties<'t>>
+}
@@ -0,0 +1,251 @@
+use crate::ferret_3d::edge_data::EdgeData;
+use crate::ferret_3d::edge_equation::EdgeEquation;
+use crate::ferret_3d::pixel_data::PixelData;
+use crate::ferret_3d::triangle_equations::TriangleEquations;
+
+#[cfg(feature = "simd")]
+use core::simd::cmp::SimdPartialEq;
+#[cfg(feature = "simd")]
+use core::simd::cmp::SimdPartialOrd;
+#[cfg(feature = "simd")]
+use core::simd::f32x8;
+
+/// Pixels of a block row processed together.
+pub const LANES: usize = 8;
+
+/// Bit set for each of the first `count` lanes.
+#[inline]
+pub const fn lane_mask(count: usize) -> u8 {
+    if count >= LANES {
+        u8::MAX
+    } else {
+        (1u8 << count) - 1
+    }
+}
+
+/// Varyings of `LANES` consecutive pixels of a row, stored one array per
+/// varying. Lanes are stepped from the first pixel one at a time, like
+/// `PixelData::step_x`, so they match the per-pixel path bit for bit. Depths
+/// and masks are then computed for all lanes at once: with `core::simd` when
+/// the `simd` feature is on, otherwise with plain loops. Both give the same
+/// results.
+pub struct PixelRow<const TPARAMETER_COUNT: usize> {
+    pub data: [[f32; LANES]; TPARAMETER_COUNT]
+}
+
+impl<const TPARAMETER_COUNT: usize> PixelRow<TPARAMETER_COUNT> {
+    pub fn new(tri_eq: &TriangleEquations<TPARAMETER_COUNT>, first: &PixelData<TPARAMETER_COUNT>) -> Self {
+        let mut data = [[0.0; LANES]; TPARAMETER_COUNT];
+
+        for ((lanes, value), equation) in data.iter_mut().zip(first.data).zip(&tri_eq.a_var) {
+            *lanes = step_lanes(value, equation.a);
+        }
+
+        Self {
+            data
+        }
+    }
+
+    pub fn pixel(&self, lane: usize) -> PixelData<TPARAMETER_COUNT> {
+        let mut data = [0.0; TPARAMETER_COUNT];
+
+        for (value, lanes) in data.iter_mut().zip(&self.data) {
+            *value = lanes[lane];
+        }
+
+        PixelData {
+            data
+        }
+    }
+}
+
+/// Biased 1/w of every lane, computed like `FerretGraphics::biased_depth`
+/// with `bias_slope` being the slope scale times the triangle's w slope.
+/// Only w is stepped, so rows can be depth tested before their varyings
+/// are computed.
+pub fn depth_lanes<const TPARAMETER_COUNT: usize>(
+    tri_eq: &TriangleEquations<TPARAMETER_COUNT>,
+    first: &PixelData<TPARAMETER_COUNT>,
+    bias_constant: f32,
+    bias_slope: f32
+) -> [f32; LANES] {
+    biased_depths(&step_lanes(first.data[3], tri_eq.a_var[3].a), bias_constant, bias_slope)
+}
+
+/// Lanes covered by the triangle, `edge` being the first pixel of the row.
+pub fn coverage_mask<const TPARAMETER_COUNT: usize>(
+    tri_eq: &TriangleEquations<TPARAMETER_COUNT>,
+    edge: &EdgeData<TPARAMETER_COUNT>
+) -> u8 {
+    // Rows outside of the triangle skip the remaining edges
+    let mut mask = edge_mask(&tri_eq.e0, edge.ev0);
+
+    if mask != 0 {
+        mask &= edge_mask(&tri_eq.e1, edge.ev1);
+    }
+
+    if mask != 0 {
+        mask &= edge_mask(&tri_eq.e2, edge.ev2);
+    }
+
+    mask
+}
+
+/// Lanes whose depth is nearer than the stored one. Lanes past the end of
+/// `stored` never pass.
+#[inline]
+pub fn depth_mask(depths: &[f32; LANES], stored: &[f32]) -> u8 {
+    if let Ok(stored) = stored.try_into() {
+        return greater_mask(depths, stored);
+    }
+
+    let mut padded = [f32::INFINITY; LANES];
+    let count = stored.len().min(LANES);
+    padded[..count].copy_from_slice(&stored[..count]);
+
+    greater_mask(depths, &padded)
+}
+
+/// `first` stepped by `step` once per lane. The additions are chained so
+/// each lane rounds like stepping pixel by pixel.
+#[inline]
+fn step_lanes(first: f32, step: f32) -> [f32; LANES] {
+    let mut lanes = [first; LANES];
+
+    for lane in 1..LANES {
+        lanes[lane] = lanes[lane - 1] + step;
+    }
+
+    lanes
+}
+
+#[cfg(not(feature = "simd"))]
+#[inline]
+fn biased_depths(w: &[f32; LANES], bias_constant: f32, bias_slope: f32) -> [f32; LANES] {
+    let mut depths = [0.0; LANES];
+
+    for (depth, w) in depths.iter_mut().zip(w) {
+        let d = 1.0 / w;
+        *depth = d + bias_constant + bias_slope * d * d;
+    }
+
+    depths
+}
+
+#[cfg(feature = "simd")]
+#[inline]
+fn biased_depths(w: &[f32; LANES], bias_constant: f32, bias_slope: f32) -> [f32; LANES] {
+    let d = f32x8::splat(1.0) / f32x8::from_array(*w);
+    (d + f32x8::splat(bias_constant) + f32x8::splat(bias_slope) * d * d).to_array()
+}
+
+#[cfg(not(feature = "simd"))]
+fn edge_mask<const TPARAMETER_COUNT: usize>(equation: &EdgeEquation<TPARAMETER_COUNT>, first: f32) -> u8 {
+    let values = step_lanes(first, equation.a);
+    let mut mask = 0;
+
+    for (lane, value) in values.iter().enumerate() {
+        if equation.test(*value) {
+            mask |= 1 << lane;
+        }
+    }
+
+    mask
+}
+
+#[cfg(feature = "simd")]
+fn edge_mask<const TPARAMETER_COUNT: usize>(equation: &EdgeEquation<TPARAMETER_COUNT>, first: f32) -> u8 {
+    let values = f32x8::from_array(step_lanes(first, equation.a));
+    let zero = f32x8::splat(0.0);
+
+    let mut inside = values.simd_gt(zero);
+    if equation.tie {
+        inside |= values.simd_eq(zero);
+    }
+
+    inside.to_bitmask() as u8
+}
+
+#[cfg(not(feature = "simd"))]
+#[inline]
+fn greater_mask(a: &[f32; LANES], b: &[f32; LANES]) -> u8 {
+    let mut mask = 0;
+
+    for (lane, (a, b)) in a.iter().zip(b).enumerate() {
+        if a > b {
+            mask |= 1 << lane;
+        }
+    }
+
+    mask
+}
+
+#[cfg(feature = "simd")]
+#[inline]
+fn greater_mask(a: &[f32; LANES], b: &[f32; LANES]) -> u8 {
+    f32x8::from_array(*a).simd_gt(f32x8::from_array(*b)).to_bitmask() as u8
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use nalgebra::Vector4;
+
+    fn triangle() -> TriangleEquations<4> {
+        TriangleEquations::new(
+            &Vector4::new(1.3, 2.7, 0.37, 0.9),
+            &Vector4::new(40.1, 5.2, 0.51, 0.33),
+            &Vector4::new(7.9, 33.3, 0.11, 0.71))
+    }
+
+    #[test]
+    fn lanes_match_per_pixel_steps() {
+        let triangle = triangle();
+        let first = PixelData::new(&triangle, 3.0, 4.0);
+        let row = PixelRow::new(&triangle, &first);
+        let depths = depth_lanes(&triangle, &first, 0.001, 0.25);
+        let mut pixel = first.clone();
+
+        for (lane, depth) in depths.iter().enumerate() {
+            assert_eq!(row.pixel(lane).data.map(f32::to_bits), pixel.data.map(f32::to_bits));
+
+            let d = 1.0 / pixel.data[3];
+            assert_eq!(depth.to_bits(), (d + 0.001 + 0.25 * d * d).to_bits());
+
+            pixel.step_x(&triangle);
+        }
+    }
+
+    #[test]
+    fn masks_match_scalar_tests() {
+        let triangle = triangle();
+
+        for y in 0..40 {
+            for x in (0..48).step_by(LANES) {
+                let edge = EdgeData::new(&triangle, x as f32 + 1.0, y as f32 + 1.0);
+                let mask = coverage_mask(&triangle, &edge);
+
+                let mut lane_edge = edge.clone();
+
+                for lane in 0..LANES {
+                    assert_eq!(mask >> lane & 1 == 1, lane_edge.test(&triangle));
+                    lane_edge.step_x_by_one(&triangle);
+                }
+            }
+        }
+
+        // Edge exactly on a pixel: only the tie-winning side covers it
+        let square = TriangleEquations::<4>::new(
+            &Vector4::new(0.0, 0.0, 0.0, 1.0),
+            &Vector4::new(0.0, 8.0, 0.0, 1.0),
+            &Vector4::new(8.0, 0.0, 0.0, 1.0));
+        let edge = EdgeData::new(&square, 0.0, 4.0);
+        let mask = coverage_mask(&square, &edge);
+        assert_eq!(mask & 1 == 1, square.e0.test(edge.ev0) && square.e1.test(edge.ev1) && square.e2.test(edge.ev2));
+
+        let depths = [0.5, 0.2, 0.9, 0.1, 0.3, 0.3, 0.7, 0.8];
+        assert_eq!(depth_mask(&depths, &[0.4, 0.4, 0.4, 0.4, 0.4, 0.3]), 0b0000_0101);
+        assert_eq!(lane_mask(3), 0b0000_0111);
+        assert_eq!(lane_mask(LANES), u8::MAX);
+    }
+}
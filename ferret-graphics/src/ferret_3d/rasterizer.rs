@@ -0,0 +1,10 @@
+/// Triangle traversal used by `FerretGraphics`. `HalfSpace` walks 8x8
+/// blocks of the bounding box. `Scanline` walks the left and right edges
+/// down the triangle and fills the span between them on each row, which
+/// wastes less work on thin and sliver triangles. Both cover the same
+/// pixels.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum Rasterizer {
+    HalfSpace,
+    Scanline
+}
@@ -0,0 +1,24 @@
+/// Resolution of the 3D scene relative to the screen.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum RenderScale {
+    Full,
+    Half,
+    Quarter
+}
+
+impl RenderScale {
+    pub const fn factor(&self) -> u16 {
+        match self {
+            RenderScale::Full => 1,
+            RenderScale::Half => 2,
+            RenderScale::Quarter => 4
+        }
+    }
+
+    /// Bytes needed for an RGB888 scene buffer covering a screen of the
+    /// given size at this scale.
+    pub const fn scene_buffer_size(&self, width: usize, height: usize) -> usize {
+        let factor = self.factor() as usize;
+        (width / factor) * (height / factor) * 3
+    }
+}
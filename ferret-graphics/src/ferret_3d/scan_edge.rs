@@ -0,0 +1,32 @@
+/// Edge of a triangle walked one row at a time by the scanline rasterizer,
+/// `x` being where it crosses the current row.
+pub struct ScanEdge {
+    pub x: f32,
+    pub dxdy: f32
+}
+
+impl ScanEdge {
+    /// Edge from `from` to `to`, positioned on row `y`. Horizontal edges
+    /// stay at the x of `to`.
+    pub fn new(from: (f32, f32), to: (f32, f32), y: f32) -> Self {
+        let dy = to.1 - from.1;
+
+        if dy == 0.0 {
+            return Self {
+                x: to.0,
+                dxdy: 0.0
+            };
+        }
+
+        let dxdy = (to.0 - from.0) / dy;
+
+        Self {
+            x: from.0 + (y - from.1) * dxdy,
+            dxdy
+        }
+    }
+
+    pub fn step_y_by_one(&mut self) {
+        self.x += self.dxdy;
+    }
+}
@@ -0,0 +1,31 @@
+use nalgebra::Vector3;
+
+/// RGB888 image sampled by pixel shaders.
+#[derive(Copy, Clone)]
+pub struct Texture<'t> {
+    pub data: &'t [u8],
+    pub width: u16,
+    pub height: u16
+}
+
+impl<'t> Texture<'t> {
+    pub fn new(data: &'t [u8], width: u16, height: u16) -> Self {
+        Self {
+            data,
+            width,
+            height
+        }
+    }
+
+    /// Nearest-neighbour lookup, with `u` and `v` clamped to 0.0..1.0.
+    pub fn sample(&self, u: f32, v: f32) -> Vector3<f32> {
+        let x = ((self.width as f32 * u) as usize).min(self.width as usize - 1);
+        let y = ((self.height as f32 * v) as usize).min(self.height as usize - 1);
+        let index = (y * self.width as usize + x) * 3;
+
+        Vector3::new(
+            self.data[index] as f32 / 255.0,
+            self.data[index + 1] as f32 / 255.0,
+            self.data[index + 2] as f32 / 255.0)
+    }
+}
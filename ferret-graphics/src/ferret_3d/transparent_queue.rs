@@ -0,0 +1,142 @@
+use crate::ferret_3d::pixel_shader_trait::PixelShaderTrait;
+
+use nalgebra::SVector;
+
+#[derive(Copy, Clone)]
+pub struct TransparentQueueEntry<'s, const TVECTOR_SIZE: usize> {
+    pub depth: f32,
+    pub v0: SVector<f32, TVECTOR_SIZE>,
+    pub v1: SVector<f32, TVECTOR_SIZE>,
+    pub v2: SVector<f32, TVECTOR_SIZE>,
+    pub pixel_shader: &'s dyn PixelShaderTrait<TVECTOR_SIZE>
+}
+
+/// Triangles waiting to be drawn after the opaque geometry. Unlike the
+/// ordering table they are sorted exactly by view depth on flush. Storage is
+/// provided by the caller, one entry per triangle that can be queued in a
+/// frame.
+pub struct TransparentQueue<'a, 's, const TVECTOR_SIZE: usize> {
+    entries: &'a mut [Option<TransparentQueueEntry<'s, TVECTOR_SIZE>>],
+    count: usize
+}
+
+impl<'a, 's, const TVECTOR_SIZE: usize> TransparentQueue<'a, 's, TVECTOR_SIZE> {
+    pub fn new(entries: &'a mut [Option<TransparentQueueEntry<'s, TVECTOR_SIZE>>]) -> Self {
+        let mut queue = Self {
+            entries,
+            count: 0
+        };
+
+        queue.clear();
+        queue
+    }
+
+    pub fn clear(&mut self) {
+        for entry in self.entries.iter_mut() {
+            *entry = None;
+        }
+
+        self.count = 0;
+    }
+
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.count >= self.entries.len()
+    }
+
+    /// Queues a triangle at `depth` (view-space w). Returns false and drops
+    /// the triangle once the queue is full.
+    pub fn insert(
+        &mut self,
+        depth: f32,
+        v0: SVector<f32, TVECTOR_SIZE>,
+        v1: SVector<f32, TVECTOR_SIZE>,
+        v2: SVector<f32, TVECTOR_SIZE>,
+        pixel_shader: &'s dyn PixelShaderTrait<TVECTOR_SIZE>
+    ) -> bool {
+        if self.is_full() {
+            return false;
+        }
+
+        self.entries[self.count] = Some(TransparentQueueEntry {
+            depth,
+            v0, v1, v2,
+            pixel_shader
+        });
+
+        self.count += 1;
+
+        true
+    }
+
+    /// Sorts the queued triangles and visits them from the farthest to the
+    /// nearest.
+    pub fn for_each_back_to_front<F>(&mut self, mut act: F)
+    where
+        F: FnMut(&TransparentQueueEntry<'s, TVECTOR_SIZE>)
+    {
+        let entries = &mut self.entries[..self.count];
+
+        entries.sort_unstable_by(|a, b| {
+            let a = a.as_ref().map_or(0.0, |entry| entry.depth);
+            let b = b.as_ref().map_or(0.0, |entry| entry.depth);
+            b.total_cmp(&a)
+        });
+
+        for entry in entries.iter().flatten() {
+            act(entry);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ferret_3d::pixel_context::PixelContext;
+    use crate::ferret_3d::pixel_data::PixelData;
+    use nalgebra::Vector3;
+
+    struct NullShader;
+
+    impl PixelShaderTrait<1> for NullShader {
+        fn process(&self, _pixel_data: &PixelData<1>, _context: &PixelContext) -> Vector3<f32> {
+            Vector3::zeros()
+        }
+    }
+
+    fn insert(queue: &mut TransparentQueue<'_, '_, 1>, depth: f32) -> bool {
+        let v = SVector::<f32, 1>::new(depth);
+        queue.insert(depth, v, v, v, &NullShader)
+    }
+
+    #[test]
+    fn sorts_back_to_front_and_resets_on_clear() {
+        let mut entries = [None; 3];
+        let mut queue = TransparentQueue::new(&mut entries);
+
+        assert!(insert(&mut queue, 2.0));
+        assert!(insert(&mut queue, 7.0));
+        assert!(insert(&mut queue, 4.5));
+        assert!(queue.is_full());
+        assert!(!insert(&mut queue, 9.0));
+
+        let mut order = [0.0; 3];
+        let mut count = 0;
+        queue.for_each_back_to_front(|entry| {
+            order[count] = entry.v0[0];
+            count += 1;
+        });
+        assert_eq!(order, [7.0, 4.5, 2.0]);
+
+        queue.clear();
+        assert!(queue.is_empty());
+        queue.for_each_back_to_front(|_| panic!("queue should be empty"));
+    }
+}
@@ -8,7 +8,9 @@ pub struct TriangleEquations<const TPARAMETER_COUNT: usize> {
     pub e0: EdgeEquation<TPARAMETER_COUNT>,
     pub e1: EdgeEquation<TPARAMETER_COUNT>,
     pub e2: EdgeEquation<TPARAMETER_COUNT>,
-    pub a_var: [ParameterEquation<TPARAMETER_COUNT>; TPARAMETER_COUNT]
+    pub a_var: [ParameterEquation<TPARAMETER_COUNT>; TPARAMETER_COUNT],
+    pub ddx: [f32; TPARAMETER_COUNT],
+    pub ddy: [f32; TPARAMETER_COUNT]
 }
 
 impl<const TPARAMETER_COUNT: usize> TriangleEquations<TPARAMETER_COUNT> {
@@ -22,17 +24,24 @@ impl<const TPARAMETER_COUNT: usize> TriangleEquations<TPARAMETER_COUNT> {
         #[allow(deprecated)] // TODO: find another way to create uninitialized array
         let mut a_var: [ParameterEquation<TPARAMETER_COUNT>; TPARAMETER_COUNT] = unsafe { core::mem::uninitialized() };
 
+        let mut ddx = [0.0; TPARAMETER_COUNT];
+        let mut ddy = [0.0; TPARAMETER_COUNT];
+
         if area2 >= 0.0 {
             let factor = 1.0 / area2;
             for i in 0..TPARAMETER_COUNT {
                 a_var[i] = ParameterEquation::new(v0[i], v1[i], v2[i], &e0, &e1, &e2, factor);
+                ddx[i] = a_var[i].a;
+                ddy[i] = a_var[i].b;
             }
         }
 
         Self {
             area2,
             e0, e1, e2,
-            a_var
+            a_var,
+            ddx,
+            ddy
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,24 @@
+use crate::ferret_3d::triangle_equations::TriangleEquations;
+
+/// Per-triangle state shared by every pixel the rasterizer draws.
+pub struct TriangleShading<'t, TPixelShader: ?Sized, const TPARAMETER_COUNT: usize> {
+    pub pixel_shader: &'t TPixelShader,
+    pub triangle: &'t TriangleEquations<TPARAMETER_COUNT>,
+    pub front_facing: bool,
+
+    /// Largest screen-space slope of w, used by the slope-scaled depth bias.
+    pub w_slope: f32
+}
+
+impl<'t, TPixelShader: ?Sized, const TPARAMETER_COUNT: usize> TriangleShading<'t, TPixelShader, TPARAMETER_COUNT> {
+    pub fn new(pixel_shader: &'t TPixelShader, triangle: &'t TriangleEquations<TPARAMETER_COUNT>, front_facing: bool) -> Self {
+        let w_slope = triangle.a_var[3].a.abs().max(triangle.a_var[3].b.abs());
+
+        Self {
+            pixel_shader,
+            triangle,
+            front_facing,
+            w_slope
+        }
+    }
+}
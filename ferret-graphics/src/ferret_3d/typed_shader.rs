@@ -0,0 +1,54 @@
+use crate::ferret_3d::pixel_context::PixelContext;
+use crate::ferret_3d::pixel_data::PixelData;
+use crate::ferret_3d::pixel_shader_trait::PixelShaderTrait;
+use crate::ferret_3d::varyings::ClipPosition;
+use crate::ferret_3d::varyings::Varyings;
+use crate::ferret_3d::vertex_shader_trait::VertexShaderTrait;
+
+use nalgebra::SVector;
+use nalgebra::Vector3;
+
+/// Vertex shader working on named varyings instead of positional slots.
+pub trait TypedVertexShaderTrait {
+    type Input;
+    type Output: ClipPosition;
+
+    fn process(&self, input: &Self::Input) -> Self::Output;
+}
+
+/// Pixel shader receiving the interpolated varyings of a typed vertex shader.
+pub trait TypedPixelShaderTrait {
+    type Input;
+
+    fn process(&self, input: &Self::Input, context: &PixelContext) -> Vector3<f32>;
+}
+
+/// Adapts a typed vertex shader to `VertexShaderTrait`, so it can be used
+/// with every positional `draw_mesh*` call.
+pub struct TypedVertexShader<'s, TShader: ?Sized>(pub &'s TShader);
+
+/// Adapts a typed pixel shader to `PixelShaderTrait`.
+pub struct TypedPixelShader<'s, TShader: ?Sized>(pub &'s TShader);
+
+impl<'s, TShader, const TVERTEX_IN_SIZE: usize, const TVERTEX_OUT_SIZE: usize>
+    VertexShaderTrait<TVERTEX_IN_SIZE, TVERTEX_OUT_SIZE> for TypedVertexShader<'s, TShader>
+where
+    TShader: TypedVertexShaderTrait + ?Sized,
+    TShader::Input: Varyings<TVERTEX_IN_SIZE>,
+    TShader::Output: Varyings<TVERTEX_OUT_SIZE>
+{
+    fn process(&self, v: &SVector<f32, TVERTEX_IN_SIZE>) -> SVector<f32, TVERTEX_OUT_SIZE> {
+        self.0.process(&TShader::Input::from_vector(v)).to_vector()
+    }
+}
+
+impl<'s, TShader, const TVERTEX_INPUT_SIZE: usize>
+    PixelShaderTrait<TVERTEX_INPUT_SIZE> for TypedPixelShader<'s, TShader>
+where
+    TShader: TypedPixelShaderTrait + ?Sized,
+    TShader::Input: Varyings<TVERTEX_INPUT_SIZE>
+{
+    fn process(&self, pixel_data: &PixelData<TVERTEX_INPUT_SIZE>, context: &PixelContext) -> Vector3<f32> {
+        self.0.process(&TShader::Input::from_pixel_data(pixel_data), context)
+    }
+}
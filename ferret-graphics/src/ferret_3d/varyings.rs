@@ -0,0 +1,103 @@
+use crate::color::Color;
+use crate::ferret_3d::pixel_data::PixelData;
+
+use nalgebra::SVector;
+use nalgebra::Vector2;
+use nalgebra::Vector3;
+use nalgebra::Vector4;
+
+/// A value that can be stored in `SIZE` consecutive interpolated slots.
+pub trait VaryingField: Sized {
+    const SIZE: usize;
+
+    fn write_slots(&self, slots: &mut [f32]);
+    fn read_slots(slots: &[f32]) -> Self;
+}
+
+/// A struct of named fields mapped onto the positional vectors used by the
+/// rasterizer. Usually implemented with `#[derive(Varyings)]`.
+pub trait Varyings<const TSIZE: usize>: Sized {
+    fn write_slots(&self, slots: &mut [f32]);
+    fn read_slots(slots: &[f32]) -> Self;
+
+    fn to_vector(&self) -> SVector<f32, TSIZE> {
+        let mut vector = SVector::<f32, TSIZE>::zeros();
+        self.write_slots(vector.as_mut_slice());
+        vector
+    }
+
+    fn from_vector(vector: &SVector<f32, TSIZE>) -> Self {
+        Self::read_slots(vector.as_slice())
+    }
+
+    fn from_pixel_data(pixel_data: &PixelData<TSIZE>) -> Self {
+        Self::read_slots(&pixel_data.data)
+    }
+}
+
+/// Marks varyings whose first four slots hold the clip-space position, as
+/// required for vertex shader outputs. Derived for structs with a
+/// `#[position]` field.
+pub trait ClipPosition {}
+
+impl VaryingField for f32 {
+    const SIZE: usize = 1;
+
+    fn write_slots(&self, slots: &mut [f32]) {
+        slots[0] = *self;
+    }
+
+    fn read_slots(slots: &[f32]) -> Self {
+        slots[0]
+    }
+}
+
+impl VaryingField for Vector2<f32> {
+    const SIZE: usize = 2;
+
+    fn write_slots(&self, slots: &mut [f32]) {
+        slots.copy_from_slice(self.as_slice());
+    }
+
+    fn read_slots(slots: &[f32]) -> Self {
+        Vector2::new(slots[0], slots[1])
+    }
+}
+
+impl VaryingField for Vector3<f32> {
+    const SIZE: usize = 3;
+
+    fn write_slots(&self, slots: &mut [f32]) {
+        slots.copy_from_slice(self.as_slice());
+    }
+
+    fn read_slots(slots: &[f32]) -> Self {
+        Vector3::new(slots[0], slots[1], slots[2])
+    }
+}
+
+impl VaryingField for Vector4<f32> {
+    const SIZE: usize = 4;
+
+    fn write_slots(&self, slots: &mut [f32]) {
+        slots.copy_from_slice(self.as_slice());
+    }
+
+    fn read_slots(slots: &[f32]) -> Self {
+        Vector4::new(slots[0], slots[1], slots[2], slots[3])
+    }
+}
+
+impl VaryingField for Color {
+    const SIZE: usize = 3;
+
+    fn write_slots(&self, slots: &mut [f32]) {
+        slots[0] = self.0;
+        slots[1] = self.1;
+        slots[2] = self.2;
+    }
+
+    fn read_slots(slots: &[f32]) -> Self {
+        Color(slots[0], slots[1], slots[2])
+    }
+}
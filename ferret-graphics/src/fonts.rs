@@ -1 +1,5 @@
-pub mod glcd;
\ No newline at end of file
+pub mod font;
+pub mod glcd;
+pub mod glcd_large;
+pub mod glcd_proportional;
+pub mod tiny;
\ No newline at end of file
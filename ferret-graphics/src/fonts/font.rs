@@ -0,0 +1,166 @@
+/// Consecutive codepoints `first..=last` drawn with consecutive glyphs,
+/// starting at glyph `glyph`.
+#[derive(Copy, Clone)]
+pub struct GlyphRange {
+    pub first: char,
+    pub last: char,
+    pub glyph: u16
+}
+
+/// Column-major bitmap font. Each glyph takes `cell_width` columns of
+/// `cell_height` rows, a column being stored in as many bytes as needed, low
+/// byte and bit 0 on top.
+#[derive(Copy, Clone)]
+pub struct Font<'f> {
+    pub data: &'f [u8],
+    pub cell_width: u8,
+    pub cell_height: u8,
+
+    /// Width of every glyph for proportional fonts, `cell_width` otherwise.
+    pub widths: Option<&'f [u8]>,
+
+    /// Empty columns drawn after each glyph.
+    pub spacing: u8,
+
+    /// Row the glyphs stand on, counted from the top of the cell.
+    pub baseline: u8,
+    pub line_height: u8,
+    pub glyphs: &'f [GlyphRange],
+
+    /// Glyph drawn for characters outside of `glyphs`.
+    pub fallback: Option<u16>
+}
+
+impl<'f> Font<'f> {
+    pub const fn bytes_per_column(&self) -> usize {
+        (self.cell_height as usize).div_ceil(8)
+    }
+
+    pub fn glyph_count(&self) -> usize {
+        self.data.len() / (self.cell_width as usize * self.bytes_per_column())
+    }
+
+    /// Glyph drawn for `c`, the fallback glyph when the font doesn't cover
+    /// it, or None when there's no fallback either.
+    pub fn glyph(&self, c: char) -> Option<usize> {
+        let glyph = match self.glyphs.iter().find(|range| range.first <= c && c <= range.last) {
+            Some(range) => range.glyph as usize + (c as usize - range.first as usize),
+            None => self.fallback? as usize
+        };
+
+        if glyph < self.glyph_count() {
+            Some(glyph)
+        } else {
+            None
+        }
+    }
+
+    pub fn glyph_width(&self, glyph: usize) -> u8 {
+        match self.widths {
+            Some(widths) => widths[glyph],
+            None => self.cell_width
+        }
+    }
+
+    /// Horizontal distance from `c` to the next character.
+    pub fn advance(&self, c: char) -> u16 {
+        let width = self.glyph(c).map_or(self.cell_width, |glyph| self.glyph_width(glyph));
+        width as u16 + self.spacing as u16
+    }
+
+    /// Bytes of `column` of `glyph`, works for any `cell_height`.
+    pub fn column_data(&self, glyph: usize, column: usize) -> &'f [u8] {
+        let bytes = self.bytes_per_column();
+        let start = (glyph * self.cell_width as usize + column) * bytes;
+
+        &self.data[start..start + bytes]
+    }
+
+    /// Rows of `column` of `glyph` as bits, bit 0 being the top row. Only
+    /// the first 32 rows fit, use `column_data` for taller fonts.
+    pub fn column(&self, glyph: usize, column: usize) -> u32 {
+        self.column_data(glyph, column)
+            .iter()
+            .take(4)
+            .rev()
+            .fold(0, |bits, byte| bits << 8 | *byte as u32)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::fonts::font::Font;
+    use crate::fonts::glcd::GLCD;
+    use crate::fonts::glcd::GLCD_CP437;
+    use crate::fonts::glcd::GLCD_FALLBACK;
+    use crate::fonts::glcd::GLCD_FONT;
+    use crate::fonts::glcd::GLCD_LATIN;
+    use crate::fonts::glcd_large::GLCD_LARGE;
+    use crate::fonts::glcd_proportional::GLCD_PROPORTIONAL;
+    use crate::fonts::tiny::TINY_FONT;
+
+    #[test]
+    fn glcd_port_keeps_layout() {
+        let glyph = GLCD.glyph('A').unwrap();
+
+        assert_eq!(glyph, 'A' as usize);
+        assert_eq!(GLCD.column(glyph, 2), GLCD_FONT['A' as usize * 5 + 2] as u32);
+        assert_eq!(GLCD.advance('A'), 6);
+        assert_eq!(GLCD_CP437.glyph('A'), Some(glyph));
+    }
+
+    #[test]
+    fn latin_and_fallback_glyphs() {
+        for c in "ãçéêíñóõúüÁÇÉÑÕÚ¡¿ªº€😀".chars() {
+            for font in [GLCD, GLCD_PROPORTIONAL, GLCD_LARGE, TINY_FONT] {
+                assert!(font.glyph(c).is_some(), "{}", c);
+            }
+        }
+
+        assert_eq!(GLCD.glyph('é'), Some(GLCD_LATIN as usize + 0x49));
+        assert_eq!(GLCD.glyph('😀'), Some(GLCD_FALLBACK as usize));
+        assert_eq!(GLCD.glyph('\u{180}'), Some(GLCD_FALLBACK as usize));
+        for column in 0..3 {
+            let plain = TINY_FONT.column(TINY_FONT.glyph('C').unwrap(), column);
+            assert_eq!(TINY_FONT.column(TINY_FONT.glyph('ç').unwrap(), column), plain);
+        }
+        assert_ne!(GLCD.glyph('ç'), GLCD.glyph('c'));
+
+        // Accented letters keep the plain letter under the accent
+        let plain = GLCD.glyph('n').unwrap();
+        let accented = GLCD.glyph('ń').unwrap();
+        for column in 0..5 {
+            assert_eq!(GLCD.column(accented, column) & !3, GLCD.column(plain, column) & !3);
+        }
+
+        let no_fallback = Font { fallback: None, ..GLCD };
+        assert!(no_fallback.glyph('😀').is_none());
+    }
+
+    #[test]
+    fn bundled_fonts_cover_ascii() {
+        for font in [GLCD_PROPORTIONAL, GLCD_LARGE] {
+            for c in ' '..='~' {
+                assert!(font.glyph(c).is_some(), "{}", c);
+            }
+        }
+
+        for c in (' '..='_').chain('a'..='z') {
+            assert!(TINY_FONT.glyph(c).is_some(), "{}", c);
+        }
+
+        assert_eq!(TINY_FONT.glyph('q'), TINY_FONT.glyph('Q'));
+        assert!(GLCD_PROPORTIONAL.advance('i') < GLCD_PROPORTIONAL.advance('W'));
+
+        // Every lit pixel of the large font is doubled in both directions
+        let glyph = GLCD.glyph('A').unwrap();
+        let large = GLCD_LARGE.glyph('A').unwrap();
+        for column in 0..5 {
+            let small = GLCD.column(glyph, column);
+            let doubled = (0..8).fold(0, |bits, row| bits | ((small >> row & 1) * (3 << (row * 2))));
+
+            assert_eq!(GLCD_LARGE.column(large, column * 2), doubled);
+            assert_eq!(GLCD_LARGE.column(large, column * 2 + 1), doubled);
+        }
+    }
+}
@@ -1,4 +1,34 @@
-pub const  GLCD_FONT: [u8; 1275] = [
+use crate::fonts::font::Font;
+use crate::fonts::font::GlyphRange;
+
+/// ASCII, Latin-1 Supplement and Latin Extended-A.
+pub const GLCD: Font<'static> = Font {
+    data: &GLCD_FONT,
+    cell_width: 5,
+    cell_height: 8,
+    widths: None,
+    spacing: 1,
+    baseline: 7,
+    line_height: 9,
+    glyphs: &[
+        GlyphRange { first: '\0', last: '\u{7f}', glyph: 0 },
+        GlyphRange { first: '\u{a0}', last: '\u{17f}', glyph: GLCD_LATIN }
+    ],
+    fallback: Some(GLCD_FALLBACK)
+};
+
+/// The original code page of `GLCD_FONT`, close to CP437: chars up to
+/// `'\u{fe}'` are glyph indices rather than Unicode.
+pub const GLCD_CP437: Font<'static> = Font {
+    glyphs: &[GlyphRange { first: '\0', last: '\u{fe}', glyph: 0 }],
+    ..GLCD
+};
+
+/// First glyph of U+00A0..=U+017F in `GLCD_FONT`.
+pub const GLCD_LATIN: u16 = 255;
+pub const GLCD_FALLBACK: u16 = GLCD_LATIN + 224;
+
+pub const  GLCD_FONT: [u8; 2400] = [
     0x00, 0x00, 0x00, 0x00, 0x00,
     0x3E, 0x5B, 0x4F, 0x5B, 0x3E,
     0x3E, 0x6B, 0x4F, 0x6B, 0x3E,
@@ -253,5 +283,230 @@ pub const  GLCD_FONT: [u8; 1275] = [
     0x00, 0x1F, 0x01, 0x01, 0x1E,
     0x00, 0x19, 0x1D, 0x17, 0x12,
     0x00, 0x3C, 0x3C, 0x3C, 0x3C,
-    0x00, 0x00, 0x00, 0x00, 0x00
+    0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x7B, 0x00, 0x00,
+    0x3C, 0x24, 0xFF, 0x24, 0x24,
+    0x48, 0x7E, 0x49, 0x43, 0x66,
+    0x22, 0x1C, 0x14, 0x1C, 0x22,
+    0x2B, 0x2F, 0xFC, 0x2F, 0x2B,
+    0x00, 0x00, 0x77, 0x00, 0x00,
+    0x00, 0x66, 0x89, 0x95, 0x6A,
+    0x00, 0x01, 0x00, 0x01, 0x00,
+    0x3E, 0x49, 0x55, 0x41, 0x3E,
+    0x26, 0x29, 0x29, 0x2F, 0x28,
+    0x08, 0x14, 0x2A, 0x14, 0x22,
+    0x08, 0x08, 0x08, 0x08, 0x38,
+    0x00, 0x08, 0x08, 0x08, 0x00,
+    0x3E, 0x5D, 0x45, 0x59, 0x3E,
+    0x00, 0x01, 0x01, 0x01, 0x00,
+    0x06, 0x0F, 0x09, 0x0F, 0x06,
+    0x44, 0x44, 0x5F, 0x44, 0x44,
+    0x00, 0x19, 0x1D, 0x17, 0x12,
+    0x00, 0x11, 0x15, 0x1F, 0x00,
+    0x00, 0x00, 0x02, 0x01, 0x00,
+    0x40, 0x7E, 0x20, 0x1E, 0x20,
+    0x06, 0x09, 0x7F, 0x01, 0x7F,
+    0x00, 0x00, 0x10, 0x10, 0x00,
+    0x00, 0x00, 0x80, 0x80, 0x00,
+    0x00, 0x12, 0x1F, 0x10, 0x00,
+    0x26, 0x29, 0x29, 0x29, 0x26,
+    0x22, 0x14, 0x2A, 0x14, 0x08,
+    0x2F, 0x10, 0x28, 0x34, 0xFA,
+    0x2F, 0x10, 0xC8, 0xAC, 0xBA,
+    0x25, 0x17, 0x68, 0xF4, 0x42,
+    0x30, 0x48, 0x4D, 0x40, 0x20,
+    0x70, 0x29, 0x26, 0x28, 0x70,
+    0x70, 0x28, 0x26, 0x29, 0x70,
+    0x70, 0x2A, 0x25, 0x2A, 0x70,
+    0x72, 0x29, 0x27, 0x2A, 0x71,
+    0xF0, 0x29, 0x24, 0x29, 0xF0,
+    0xF0, 0x28, 0x25, 0x28, 0xF0,
+    0x7C, 0x0A, 0x09, 0x7F, 0x49,
+    0x1E, 0xA1, 0xA1, 0x61, 0x12,
+    0x7C, 0x55, 0x56, 0x54, 0x44,
+    0x7C, 0x54, 0x55, 0x45, 0x00,
+    0x7C, 0x56, 0x55, 0x56, 0x44,
+    0x7C, 0x55, 0x54, 0x55, 0x44,
+    0x00, 0x45, 0x7E, 0x44, 0x00,
+    0x00, 0x44, 0x7E, 0x45, 0x00,
+    0x00, 0x46, 0x7D, 0x46, 0x00,
+    0x00, 0x45, 0x7C, 0x45, 0x00,
+    0x7F, 0x49, 0x49, 0x41, 0x3E,
+    0x7D, 0x0D, 0x19, 0x31, 0x7D,
+    0x38, 0x45, 0x46, 0x44, 0x38,
+    0x38, 0x44, 0x46, 0x45, 0x38,
+    0x38, 0x46, 0x45, 0x46, 0x38,
+    0x3A, 0x45, 0x47, 0x46, 0x39,
+    0x39, 0x44, 0x44, 0x44, 0x39,
+    0x22, 0x14, 0x08, 0x14, 0x22,
+    0x7E, 0x61, 0x5D, 0x43, 0x3F,
+    0x3C, 0x41, 0x42, 0x40, 0x3C,
+    0x3C, 0x40, 0x42, 0x41, 0x3C,
+    0x3C, 0x42, 0x41, 0x42, 0x3C,
+    0x3D, 0x40, 0x40, 0x40, 0x3D,
+    0x0C, 0x10, 0x62, 0x11, 0x0C,
+    0x7F, 0x12, 0x12, 0x12, 0x0C,
+    0x7C, 0x2A, 0x2A, 0x3E, 0x14,
+    0x21, 0x55, 0x54, 0x78, 0x40,
+    0x20, 0x54, 0x54, 0x79, 0x41,
+    0x21, 0x55, 0x55, 0x79, 0x41,
+    0x22, 0x55, 0x57, 0x7A, 0x41,
+    0x21, 0x54, 0x54, 0x78, 0x41,
+    0x20, 0x54, 0x55, 0x79, 0x40,
+    0x20, 0x54, 0x54, 0x7C, 0x54,
+    0x0C, 0x1E, 0x52, 0x72, 0x12,
+    0x39, 0x55, 0x54, 0x54, 0x58,
+    0x38, 0x54, 0x54, 0x55, 0x59,
+    0x39, 0x55, 0x55, 0x55, 0x59,
+    0x39, 0x54, 0x54, 0x54, 0x59,
+    0x00, 0x01, 0x45, 0x7C, 0x40,
+    0x00, 0x00, 0x44, 0x7D, 0x41,
+    0x00, 0x02, 0x45, 0x7D, 0x42,
+    0x00, 0x00, 0x45, 0x7C, 0x41,
+    0x20, 0x55, 0x52, 0x55, 0x38,
+    0x00, 0x7A, 0x0A, 0x0A, 0x72,
+    0x32, 0x4A, 0x48, 0x48, 0x30,
+    0x30, 0x48, 0x48, 0x4A, 0x32,
+    0x32, 0x49, 0x49, 0x49, 0x32,
+    0x3A, 0x45, 0x47, 0x46, 0x39,
+    0x32, 0x48, 0x48, 0x48, 0x32,
+    0x08, 0x08, 0x6B, 0x6B, 0x08,
+    0x78, 0x64, 0x54, 0x4C, 0x3C,
+    0x3A, 0x42, 0x40, 0x20, 0x78,
+    0x38, 0x40, 0x40, 0x22, 0x7A,
+    0x3A, 0x41, 0x41, 0x21, 0x7A,
+    0x3A, 0x40, 0x40, 0x20, 0x7A,
+    0x4C, 0x90, 0x92, 0x91, 0x7C,
+    0xFE, 0x44, 0x44, 0x44, 0x38,
+    0x00, 0x9D, 0xA0, 0xA0, 0x7D,
+    0x70, 0x29, 0x25, 0x29, 0x70,
+    0x20, 0x55, 0x55, 0x79, 0x40,
+    0x71, 0x2A, 0x26, 0x2A, 0x71,
+    0x21, 0x56, 0x56, 0x7A, 0x41,
+    0x70, 0x28, 0x24, 0xA8, 0xF0,
+    0x20, 0x54, 0x54, 0xF8, 0xC0,
+    0x38, 0x44, 0x46, 0x45, 0x28,
+    0x38, 0x44, 0x46, 0x45, 0x28,
+    0x38, 0x46, 0x45, 0x46, 0x28,
+    0x38, 0x46, 0x45, 0x46, 0x28,
+    0x38, 0x44, 0x45, 0x44, 0x28,
+    0x38, 0x44, 0x45, 0x44, 0x28,
+    0x38, 0x45, 0x46, 0x45, 0x28,
+    0x38, 0x45, 0x46, 0x45, 0x28,
+    0x7C, 0x45, 0x46, 0x45, 0x38,
+    0x38, 0x44, 0x44, 0x28, 0x7F,
+    0x7F, 0x49, 0x49, 0x41, 0x3E,
+    0x38, 0x44, 0x46, 0x2B, 0x7E,
+    0x7C, 0x55, 0x55, 0x55, 0x44,
+    0x38, 0x55, 0x55, 0x55, 0x18,
+    0x7D, 0x56, 0x56, 0x56, 0x45,
+    0x39, 0x56, 0x56, 0x56, 0x19,
+    0x7C, 0x54, 0x55, 0x54, 0x44,
+    0x38, 0x54, 0x55, 0x54, 0x18,
+    0x7C, 0x54, 0x54, 0xD4, 0xC4,
+    0x38, 0x54, 0x54, 0xD4, 0x98,
+    0x7C, 0x55, 0x56, 0x55, 0x44,
+    0x38, 0x55, 0x56, 0x55, 0x18,
+    0x38, 0x46, 0x45, 0x66, 0x6C,
+    0x18, 0xA6, 0xA5, 0x9E, 0x78,
+    0x39, 0x46, 0x46, 0x66, 0x6D,
+    0x19, 0xA6, 0xA6, 0x9E, 0x79,
+    0x38, 0x44, 0x45, 0x64, 0x6C,
+    0x18, 0xA4, 0xA5, 0x9C, 0x78,
+    0x38, 0x44, 0xC4, 0xE4, 0x6C,
+    0x18, 0xA4, 0xA6, 0x9D, 0x78,
+    0x7C, 0x12, 0x11, 0x12, 0x7C,
+    0x7F, 0x08, 0x06, 0x05, 0x7A,
+    0x7F, 0x0A, 0x0A, 0x0A, 0x7F,
+    0x02, 0x7F, 0x0A, 0x04, 0x78,
+    0x02, 0x45, 0x7F, 0x46, 0x01,
+    0x02, 0x45, 0x7F, 0x42, 0x01,
+    0x00, 0x45, 0x7D, 0x45, 0x00,
+    0x00, 0x45, 0x7D, 0x41, 0x00,
+    0x01, 0x46, 0x7E, 0x46, 0x01,
+    0x01, 0x46, 0x7E, 0x42, 0x01,
+    0x00, 0x44, 0x7C, 0xC4, 0x80,
+    0x00, 0x44, 0x7C, 0xC0, 0x80,
+    0x00, 0x44, 0x7D, 0x44, 0x00,
+    0x00, 0x44, 0x7C, 0x40, 0x00,
+    0x7F, 0x00, 0x20, 0x41, 0x3F,
+    0x7D, 0x00, 0x40, 0x84, 0x7D,
+    0x20, 0x42, 0x45, 0x3E, 0x04,
+    0x20, 0x42, 0x41, 0x3E, 0x00,
+    0x7C, 0x10, 0xA8, 0x80, 0x44,
+    0x7F, 0x10, 0xA8, 0xC4, 0x00,
+    0x7C, 0x10, 0x28, 0x44, 0x00,
+    0x7C, 0x40, 0x42, 0x41, 0x40,
+    0x00, 0x41, 0x7F, 0x42, 0x01,
+    0x7C, 0x40, 0xC0, 0xC0, 0x40,
+    0x00, 0x41, 0xFF, 0xC0, 0x00,
+    0x7C, 0x40, 0x40, 0x40, 0x43,
+    0x00, 0x41, 0x7F, 0x40, 0x03,
+    0x7F, 0x40, 0x40, 0x48, 0x40,
+    0x00, 0x41, 0x7F, 0x40, 0x08,
+    0x10, 0x7F, 0x48, 0x44, 0x40,
+    0x20, 0x51, 0x7F, 0x48, 0x04,
+    0x7C, 0x08, 0x12, 0x21, 0x7C,
+    0x7C, 0x08, 0x06, 0x05, 0x78,
+    0x7C, 0x08, 0x90, 0xA0, 0x7C,
+    0x7C, 0x08, 0x84, 0x84, 0x78,
+    0x7C, 0x09, 0x12, 0x21, 0x7C,
+    0x7C, 0x09, 0x06, 0x05, 0x78,
+    0x03, 0x78, 0x04, 0x04, 0x78,
+    0x7F, 0x02, 0x84, 0x88, 0x7F,
+    0x7C, 0x08, 0x84, 0x84, 0x78,
+    0x38, 0x45, 0x45, 0x45, 0x38,
+    0x38, 0x45, 0x45, 0x45, 0x38,
+    0x39, 0x46, 0x46, 0x46, 0x39,
+    0x39, 0x46, 0x46, 0x46, 0x39,
+    0x38, 0x46, 0x45, 0x46, 0x39,
+    0x38, 0x46, 0x45, 0x46, 0x39,
+    0x3E, 0x41, 0x7F, 0x49, 0x49,
+    0x38, 0x44, 0x38, 0x54, 0x58,
+    0x7C, 0x14, 0x36, 0x15, 0x48,
+    0x7C, 0x08, 0x06, 0x05, 0x08,
+    0x7C, 0x14, 0xB4, 0x94, 0x48,
+    0x7C, 0x08, 0x84, 0x84, 0x08,
+    0x7C, 0x15, 0x36, 0x15, 0x48,
+    0x7C, 0x09, 0x06, 0x05, 0x08,
+    0x08, 0x54, 0x56, 0x55, 0x20,
+    0x48, 0x54, 0x56, 0x55, 0x24,
+    0x08, 0x56, 0x55, 0x56, 0x20,
+    0x48, 0x56, 0x55, 0x56, 0x24,
+    0x08, 0x54, 0xD4, 0xD4, 0x20,
+    0x48, 0x54, 0xD4, 0xD4, 0x24,
+    0x08, 0x55, 0x56, 0x55, 0x20,
+    0x48, 0x55, 0x56, 0x55, 0x24,
+    0x0C, 0x04, 0xFC, 0x84, 0x0C,
+    0x04, 0x04, 0xBF, 0xC4, 0x24,
+    0x0C, 0x05, 0x7E, 0x05, 0x0C,
+    0x04, 0x04, 0x3F, 0x44, 0x27,
+    0x01, 0x09, 0x7F, 0x09, 0x01,
+    0x04, 0x14, 0x3F, 0x54, 0x24,
+    0x3E, 0x41, 0x43, 0x42, 0x3D,
+    0x3E, 0x41, 0x43, 0x22, 0x7D,
+    0x3C, 0x41, 0x41, 0x41, 0x3C,
+    0x3C, 0x41, 0x41, 0x21, 0x7C,
+    0x3D, 0x42, 0x42, 0x42, 0x3D,
+    0x3D, 0x42, 0x42, 0x22, 0x7D,
+    0x3C, 0x43, 0x41, 0x43, 0x3C,
+    0x3C, 0x43, 0x41, 0x23, 0x7C,
+    0x3C, 0x42, 0x41, 0x42, 0x3D,
+    0x3C, 0x42, 0x41, 0x22, 0x7D,
+    0x3C, 0x40, 0x40, 0xC0, 0xBC,
+    0x3C, 0x40, 0x40, 0xA0, 0xFC,
+    0x3C, 0x42, 0x31, 0x42, 0x3C,
+    0x3C, 0x42, 0x31, 0x42, 0x3C,
+    0x0C, 0x12, 0x61, 0x12, 0x0C,
+    0x4C, 0x92, 0x91, 0x92, 0x7C,
+    0x0C, 0x11, 0x60, 0x11, 0x0C,
+    0x44, 0x74, 0x56, 0x5D, 0x44,
+    0x44, 0x64, 0x56, 0x4D, 0x44,
+    0x44, 0x74, 0x55, 0x5C, 0x44,
+    0x44, 0x64, 0x55, 0x4C, 0x44,
+    0x44, 0x75, 0x56, 0x5D, 0x44,
+    0x44, 0x65, 0x56, 0x4D, 0x44,
+    0x00, 0x7E, 0x01, 0x01, 0x00,
+    0x7E, 0x42, 0x42, 0x42, 0x7E
 ];
\ No newline at end of file
@@ -0,0 +1,341 @@
+use crate::fonts::font::Font;
+use crate::fonts::font::GlyphRange;
+
+/// Printable characters of `GLCD` at twice the size.
+pub const GLCD_LARGE: Font<'static> = Font {
+    data: &GLCD_LARGE_DATA,
+    cell_width: 10,
+    cell_height: 16,
+    widths: None,
+    spacing: 2,
+    baseline: 14,
+    line_height: 18,
+    glyphs: &[
+        GlyphRange { first: ' ', last: '~', glyph: 0 },
+        GlyphRange { first: '\u{a0}', last: '\u{17f}', glyph: 95 }
+    ],
+    fallback: Some(95 + 224)
+};
+
+pub const GLCD_LARGE_DATA: [u8; 6400] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x33, 0xFF, 0x33, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x3F, 0x00, 0x3F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3F, 0x00, 0x3F, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x30, 0x03, 0x30, 0x03, 0xFF, 0x3F, 0xFF, 0x3F, 0x30, 0x03, 0x30, 0x03, 0xFF, 0x3F, 0xFF, 0x3F, 0x30, 0x03, 0x30, 0x03,
+    0x30, 0x0C, 0x30, 0x0C, 0xCC, 0x0C, 0xCC, 0x0C, 0xFF, 0x3F, 0xFF, 0x3F, 0xCC, 0x0C, 0xCC, 0x0C, 0x0C, 0x03, 0x0C, 0x03,
+    0x0F, 0x0C, 0x0F, 0x0C, 0x0F, 0x03, 0x0F, 0x03, 0xC0, 0x00, 0xC0, 0x00, 0x30, 0x3C, 0x30, 0x3C, 0x0C, 0x3C, 0x0C, 0x3C,
+    0x3C, 0x0F, 0x3C, 0x0F, 0xC3, 0x30, 0xC3, 0x30, 0x3C, 0x33, 0x3C, 0x33, 0x00, 0x0C, 0x00, 0x0C, 0x00, 0x33, 0x00, 0x33,
+    0x00, 0x00, 0x00, 0x00, 0xC0, 0x00, 0xC0, 0x00, 0x3F, 0x00, 0x3F, 0x00, 0x0F, 0x00, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0xF0, 0x03, 0xF0, 0x03, 0x0C, 0x0C, 0x0C, 0x0C, 0x03, 0x30, 0x03, 0x30, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x03, 0x30, 0x03, 0x30, 0x0C, 0x0C, 0x0C, 0x0C, 0xF0, 0x03, 0xF0, 0x03, 0x00, 0x00, 0x00, 0x00,
+    0xCC, 0x0C, 0xCC, 0x0C, 0xF0, 0x03, 0xF0, 0x03, 0xFF, 0x3F, 0xFF, 0x3F, 0xF0, 0x03, 0xF0, 0x03, 0xCC, 0x0C, 0xCC, 0x0C,
+    0xC0, 0x00, 0xC0, 0x00, 0xC0, 0x00, 0xC0, 0x00, 0xFC, 0x0F, 0xFC, 0x0F, 0xC0, 0x00, 0xC0, 0x00, 0xC0, 0x00, 0xC0, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0x00, 0xC0, 0x00, 0x3F, 0x00, 0x3F, 0x00, 0x0F, 0x00, 0x0F, 0x00, 0x00, 0x00, 0x00,
+    0xC0, 0x00, 0xC0, 0x00, 0xC0, 0x00, 0xC0, 0x00, 0xC0, 0x00, 0xC0, 0x00, 0xC0, 0x00, 0xC0, 0x00, 0xC0, 0x00, 0xC0, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3C, 0x00, 0x3C, 0x00, 0x3C, 0x00, 0x3C, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x0C, 0x00, 0x0C, 0x00, 0x03, 0x00, 0x03, 0xC0, 0x00, 0xC0, 0x00, 0x30, 0x00, 0x30, 0x00, 0x0C, 0x00, 0x0C, 0x00,
+    0xFC, 0x0F, 0xFC, 0x0F, 0x03, 0x33, 0x03, 0x33, 0xC3, 0x30, 0xC3, 0x30, 0x33, 0x30, 0x33, 0x30, 0xFC, 0x0F, 0xFC, 0x0F,
+    0x00, 0x00, 0x00, 0x00, 0x0C, 0x30, 0x0C, 0x30, 0xFF, 0x3F, 0xFF, 0x3F, 0x00, 0x30, 0x00, 0x30, 0x00, 0x00, 0x00, 0x00,
+    0x0C, 0x3F, 0x0C, 0x3F, 0xC3, 0x30, 0xC3, 0x30, 0xC3, 0x30, 0xC3, 0x30, 0xC3, 0x30, 0xC3, 0x30, 0x3C, 0x30, 0x3C, 0x30,
+    0x03, 0x0C, 0x03, 0x0C, 0x03, 0x30, 0x03, 0x30, 0xC3, 0x30, 0xC3, 0x30, 0xF3, 0x30, 0xF3, 0x30, 0x0F, 0x0F, 0x0F, 0x0F,
+    0xC0, 0x03, 0xC0, 0x03, 0x30, 0x03, 0x30, 0x03, 0x0C, 0x03, 0x0C, 0x03, 0xFF, 0x3F, 0xFF, 0x3F, 0x00, 0x03, 0x00, 0x03,
+    0x3F, 0x0C, 0x3F, 0x0C, 0x33, 0x30, 0x33, 0x30, 0x33, 0x30, 0x33, 0x30, 0x33, 0x30, 0x33, 0x30, 0xC3, 0x0F, 0xC3, 0x0F,
+    0xF0, 0x0F, 0xF0, 0x0F, 0xCC, 0x30, 0xCC, 0x30, 0xC3, 0x30, 0xC3, 0x30, 0xC3, 0x30, 0xC3, 0x30, 0x03, 0x0F, 0x03, 0x0F,
+    0x03, 0x30, 0x03, 0x30, 0x03, 0x0C, 0x03, 0x0C, 0x03, 0x03, 0x03, 0x03, 0xC3, 0x00, 0xC3, 0x00, 0x3F, 0x00, 0x3F, 0x00,
+    0x3C, 0x0F, 0x3C, 0x0F, 0xC3, 0x30, 0xC3, 0x30, 0xC3, 0x30, 0xC3, 0x30, 0xC3, 0x30, 0xC3, 0x30, 0x3C, 0x0F, 0x3C, 0x0F,
+    0x3C, 0x30, 0x3C, 0x30, 0xC3, 0x30, 0xC3, 0x30, 0xC3, 0x30, 0xC3, 0x30, 0xC3, 0x0C, 0xC3, 0x0C, 0xFC, 0x03, 0xFC, 0x03,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x03, 0x30, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x00, 0x30, 0x30, 0x0F, 0x30, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0xC0, 0x00, 0xC0, 0x00, 0x30, 0x03, 0x30, 0x03, 0x0C, 0x0C, 0x0C, 0x0C, 0x03, 0x30, 0x03, 0x30,
+    0x30, 0x03, 0x30, 0x03, 0x30, 0x03, 0x30, 0x03, 0x30, 0x03, 0x30, 0x03, 0x30, 0x03, 0x30, 0x03, 0x30, 0x03, 0x30, 0x03,
+    0x00, 0x00, 0x00, 0x00, 0x03, 0x30, 0x03, 0x30, 0x0C, 0x0C, 0x0C, 0x0C, 0x30, 0x03, 0x30, 0x03, 0xC0, 0x00, 0xC0, 0x00,
+    0x0C, 0x00, 0x0C, 0x00, 0x03, 0x00, 0x03, 0x00, 0xC3, 0x33, 0xC3, 0x33, 0xC3, 0x00, 0xC3, 0x00, 0x3C, 0x00, 0x3C, 0x00,
+    0xFC, 0x0F, 0xFC, 0x0F, 0x03, 0x30, 0x03, 0x30, 0xF3, 0x33, 0xF3, 0x33, 0xC3, 0x33, 0xC3, 0x33, 0xFC, 0x30, 0xFC, 0x30,
+    0xF0, 0x3F, 0xF0, 0x3F, 0x0C, 0x03, 0x0C, 0x03, 0x03, 0x03, 0x03, 0x03, 0x0C, 0x03, 0x0C, 0x03, 0xF0, 0x3F, 0xF0, 0x3F,
+    0xFF, 0x3F, 0xFF, 0x3F, 0xC3, 0x30, 0xC3, 0x30, 0xC3, 0x30, 0xC3, 0x30, 0xC3, 0x30, 0xC3, 0x30, 0x3C, 0x0F, 0x3C, 0x0F,
+    0xFC, 0x0F, 0xFC, 0x0F, 0x03, 0x30, 0x03, 0x30, 0x03, 0x30, 0x03, 0x30, 0x03, 0x30, 0x03, 0x30, 0x0C, 0x0C, 0x0C, 0x0C,
+    0xFF, 0x3F, 0xFF, 0x3F, 0x03, 0x30, 0x03, 0x30, 0x03, 0x30, 0x03, 0x30, 0x03, 0x30, 0x03, 0x30, 0xFC, 0x0F, 0xFC, 0x0F,
+    0xFF, 0x3F, 0xFF, 0x3F, 0xC3, 0x30, 0xC3, 0x30, 0xC3, 0x30, 0xC3, 0x30, 0xC3, 0x30, 0xC3, 0x30, 0x03, 0x30, 0x03, 0x30,
+    0xFF, 0x3F, 0xFF, 0x3F, 0xC3, 0x00, 0xC3, 0x00, 0xC3, 0x00, 0xC3, 0x00, 0xC3, 0x00, 0xC3, 0x00, 0x03, 0x00, 0x03, 0x00,
+    0xFC, 0x0F, 0xFC, 0x0F, 0x03, 0x30, 0x03, 0x30, 0x03, 0x30, 0x03, 0x30, 0x03, 0x33, 0x03, 0x33, 0x0F, 0x3F, 0x0F, 0x3F,
+    0xFF, 0x3F, 0xFF, 0x3F, 0xC0, 0x00, 0xC0, 0x00, 0xC0, 0x00, 0xC0, 0x00, 0xC0, 0x00, 0xC0, 0x00, 0xFF, 0x3F, 0xFF, 0x3F,
+    0x00, 0x00, 0x00, 0x00, 0x03, 0x30, 0x03, 0x30, 0xFF, 0x3F, 0xFF, 0x3F, 0x03, 0x30, 0x03, 0x30, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x0C, 0x00, 0x0C, 0x00, 0x30, 0x00, 0x30, 0x03, 0x30, 0x03, 0x30, 0xFF, 0x0F, 0xFF, 0x0F, 0x03, 0x00, 0x03, 0x00,
+    0xFF, 0x3F, 0xFF, 0x3F, 0xC0, 0x00, 0xC0, 0x00, 0x30, 0x03, 0x30, 0x03, 0x0C, 0x0C, 0x0C, 0x0C, 0x03, 0x30, 0x03, 0x30,
+    0xFF, 0x3F, 0xFF, 0x3F, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30,
+    0xFF, 0x3F, 0xFF, 0x3F, 0x0C, 0x00, 0x0C, 0x00, 0xF0, 0x03, 0xF0, 0x03, 0x0C, 0x00, 0x0C, 0x00, 0xFF, 0x3F, 0xFF, 0x3F,
+    0xFF, 0x3F, 0xFF, 0x3F, 0x30, 0x00, 0x30, 0x00, 0xC0, 0x00, 0xC0, 0x00, 0x00, 0x03, 0x00, 0x03, 0xFF, 0x3F, 0xFF, 0x3F,
+    0xFC, 0x0F, 0xFC, 0x0F, 0x03, 0x30, 0x03, 0x30, 0x03, 0x30, 0x03, 0x30, 0x03, 0x30, 0x03, 0x30, 0xFC, 0x0F, 0xFC, 0x0F,
+    0xFF, 0x3F, 0xFF, 0x3F, 0xC3, 0x00, 0xC3, 0x00, 0xC3, 0x00, 0xC3, 0x00, 0xC3, 0x00, 0xC3, 0x00, 0x3C, 0x00, 0x3C, 0x00,
+    0xFC, 0x0F, 0xFC, 0x0F, 0x03, 0x30, 0x03, 0x30, 0x03, 0x33, 0x03, 0x33, 0x03, 0x0C, 0x03, 0x0C, 0xFC, 0x33, 0xFC, 0x33,
+    0xFF, 0x3F, 0xFF, 0x3F, 0xC3, 0x00, 0xC3, 0x00, 0xC3, 0x03, 0xC3, 0x03, 0xC3, 0x0C, 0xC3, 0x0C, 0x3C, 0x30, 0x3C, 0x30,
+    0x3C, 0x0C, 0x3C, 0x0C, 0xC3, 0x30, 0xC3, 0x30, 0xC3, 0x30, 0xC3, 0x30, 0xC3, 0x30, 0xC3, 0x30, 0x0C, 0x0F, 0x0C, 0x0F,
+    0x0F, 0x00, 0x0F, 0x00, 0x03, 0x00, 0x03, 0x00, 0xFF, 0x3F, 0xFF, 0x3F, 0x03, 0x00, 0x03, 0x00, 0x0F, 0x00, 0x0F, 0x00,
+    0xFF, 0x0F, 0xFF, 0x0F, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0xFF, 0x0F, 0xFF, 0x0F,
+    0xFF, 0x03, 0xFF, 0x03, 0x00, 0x0C, 0x00, 0x0C, 0x00, 0x30, 0x00, 0x30, 0x00, 0x0C, 0x00, 0x0C, 0xFF, 0x03, 0xFF, 0x03,
+    0xFF, 0x0F, 0xFF, 0x0F, 0x00, 0x30, 0x00, 0x30, 0xC0, 0x0F, 0xC0, 0x0F, 0x00, 0x30, 0x00, 0x30, 0xFF, 0x0F, 0xFF, 0x0F,
+    0x0F, 0x3C, 0x0F, 0x3C, 0x30, 0x03, 0x30, 0x03, 0xC0, 0x00, 0xC0, 0x00, 0x30, 0x03, 0x30, 0x03, 0x0F, 0x3C, 0x0F, 0x3C,
+    0x0F, 0x00, 0x0F, 0x00, 0x30, 0x00, 0x30, 0x00, 0xC0, 0x3F, 0xC0, 0x3F, 0x30, 0x00, 0x30, 0x00, 0x0F, 0x00, 0x0F, 0x00,
+    0x03, 0x3C, 0x03, 0x3C, 0xC3, 0x33, 0xC3, 0x33, 0xC3, 0x30, 0xC3, 0x30, 0xF3, 0x30, 0xF3, 0x30, 0x0F, 0x30, 0x0F, 0x30,
+    0x00, 0x00, 0x00, 0x00, 0xFF, 0x3F, 0xFF, 0x3F, 0x03, 0x30, 0x03, 0x30, 0x03, 0x30, 0x03, 0x30, 0x03, 0x30, 0x03, 0x30,
+    0x0C, 0x00, 0x0C, 0x00, 0x30, 0x00, 0x30, 0x00, 0xC0, 0x00, 0xC0, 0x00, 0x00, 0x03, 0x00, 0x03, 0x00, 0x0C, 0x00, 0x0C,
+    0x00, 0x00, 0x00, 0x00, 0x03, 0x30, 0x03, 0x30, 0x03, 0x30, 0x03, 0x30, 0x03, 0x30, 0x03, 0x30, 0xFF, 0x3F, 0xFF, 0x3F,
+    0x30, 0x00, 0x30, 0x00, 0x0C, 0x00, 0x0C, 0x00, 0x03, 0x00, 0x03, 0x00, 0x0C, 0x00, 0x0C, 0x00, 0x30, 0x00, 0x30, 0x00,
+    0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30,
+    0x00, 0x00, 0x00, 0x00, 0x0F, 0x00, 0x0F, 0x00, 0x3F, 0x00, 0x3F, 0x00, 0xC0, 0x00, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x0C, 0x00, 0x0C, 0x30, 0x33, 0x30, 0x33, 0x30, 0x33, 0x30, 0x33, 0xC0, 0x3F, 0xC0, 0x3F, 0x00, 0x30, 0x00, 0x30,
+    0xFF, 0x3F, 0xFF, 0x3F, 0xC0, 0x0C, 0xC0, 0x0C, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0xC0, 0x0F, 0xC0, 0x0F,
+    0xC0, 0x0F, 0xC0, 0x0F, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0xC0, 0x0C, 0xC0, 0x0C,
+    0xC0, 0x0F, 0xC0, 0x0F, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0xC0, 0x0C, 0xC0, 0x0C, 0xFF, 0x3F, 0xFF, 0x3F,
+    0xC0, 0x0F, 0xC0, 0x0F, 0x30, 0x33, 0x30, 0x33, 0x30, 0x33, 0x30, 0x33, 0x30, 0x33, 0x30, 0x33, 0xC0, 0x03, 0xC0, 0x03,
+    0x00, 0x00, 0x00, 0x00, 0xC0, 0x00, 0xC0, 0x00, 0xFC, 0x3F, 0xFC, 0x3F, 0xC3, 0x00, 0xC3, 0x00, 0x0C, 0x00, 0x0C, 0x00,
+    0xC0, 0x03, 0xC0, 0x03, 0x30, 0xCC, 0x30, 0xCC, 0x30, 0xCC, 0x30, 0xCC, 0xF0, 0xC3, 0xF0, 0xC3, 0xC0, 0x3F, 0xC0, 0x3F,
+    0xFF, 0x3F, 0xFF, 0x3F, 0xC0, 0x00, 0xC0, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0xC0, 0x3F, 0xC0, 0x3F,
+    0x00, 0x00, 0x00, 0x00, 0x30, 0x30, 0x30, 0x30, 0xF3, 0x3F, 0xF3, 0x3F, 0x00, 0x30, 0x00, 0x30, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x0C, 0x00, 0x0C, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0xF3, 0x0F, 0xF3, 0x0F, 0x00, 0x00, 0x00, 0x00,
+    0xFF, 0x3F, 0xFF, 0x3F, 0x00, 0x03, 0x00, 0x03, 0xC0, 0x0C, 0xC0, 0x0C, 0x30, 0x30, 0x30, 0x30, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x03, 0x30, 0x03, 0x30, 0xFF, 0x3F, 0xFF, 0x3F, 0x00, 0x30, 0x00, 0x30, 0x00, 0x00, 0x00, 0x00,
+    0xF0, 0x3F, 0xF0, 0x3F, 0x30, 0x00, 0x30, 0x00, 0xC0, 0x3F, 0xC0, 0x3F, 0x30, 0x00, 0x30, 0x00, 0xC0, 0x3F, 0xC0, 0x3F,
+    0xF0, 0x3F, 0xF0, 0x3F, 0xC0, 0x00, 0xC0, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0xC0, 0x3F, 0xC0, 0x3F,
+    0xC0, 0x0F, 0xC0, 0x0F, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0xC0, 0x0F, 0xC0, 0x0F,
+    0xF0, 0xFF, 0xF0, 0xFF, 0xC0, 0x03, 0xC0, 0x03, 0x30, 0x0C, 0x30, 0x0C, 0x30, 0x0C, 0x30, 0x0C, 0xC0, 0x03, 0xC0, 0x03,
+    0xC0, 0x03, 0xC0, 0x03, 0x30, 0x0C, 0x30, 0x0C, 0x30, 0x0C, 0x30, 0x0C, 0xC0, 0x03, 0xC0, 0x03, 0xF0, 0xFF, 0xF0, 0xFF,
+    0xF0, 0x3F, 0xF0, 0x3F, 0xC0, 0x00, 0xC0, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0xC0, 0x00, 0xC0, 0x00,
+    0xC0, 0x30, 0xC0, 0x30, 0x30, 0x33, 0x30, 0x33, 0x30, 0x33, 0x30, 0x33, 0x30, 0x33, 0x30, 0x33, 0x30, 0x0C, 0x30, 0x0C,
+    0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0xFF, 0x0F, 0xFF, 0x0F, 0x30, 0x30, 0x30, 0x30, 0x30, 0x0C, 0x30, 0x0C,
+    0xF0, 0x0F, 0xF0, 0x0F, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x0C, 0x00, 0x0C, 0xF0, 0x3F, 0xF0, 0x3F,
+    0xF0, 0x03, 0xF0, 0x03, 0x00, 0x0C, 0x00, 0x0C, 0x00, 0x30, 0x00, 0x30, 0x00, 0x0C, 0x00, 0x0C, 0xF0, 0x03, 0xF0, 0x03,
+    0xF0, 0x0F, 0xF0, 0x0F, 0x00, 0x30, 0x00, 0x30, 0x00, 0x0F, 0x00, 0x0F, 0x00, 0x30, 0x00, 0x30, 0xF0, 0x0F, 0xF0, 0x0F,
+    0x30, 0x30, 0x30, 0x30, 0xC0, 0x0C, 0xC0, 0x0C, 0x00, 0x03, 0x00, 0x03, 0xC0, 0x0C, 0xC0, 0x0C, 0x30, 0x30, 0x30, 0x30,
+    0xF0, 0x30, 0xF0, 0x30, 0x00, 0xC3, 0x00, 0xC3, 0x00, 0xC3, 0x00, 0xC3, 0x00, 0xC3, 0x00, 0xC3, 0xF0, 0x3F, 0xF0, 0x3F,
+    0x30, 0x30, 0x30, 0x30, 0x30, 0x3C, 0x30, 0x3C, 0x30, 0x33, 0x30, 0x33, 0xF0, 0x30, 0xF0, 0x30, 0x30, 0x30, 0x30, 0x30,
+    0x00, 0x00, 0x00, 0x00, 0xC0, 0x00, 0xC0, 0x00, 0x3C, 0x0F, 0x3C, 0x0F, 0x03, 0x30, 0x03, 0x30, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3F, 0x3F, 0x3F, 0x3F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x03, 0x30, 0x03, 0x30, 0x3C, 0x0F, 0x3C, 0x0F, 0xC0, 0x00, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x0C, 0x00, 0x0C, 0x00, 0x03, 0x00, 0x03, 0x00, 0x0C, 0x00, 0x0C, 0x00, 0x30, 0x00, 0x30, 0x00, 0x0C, 0x00, 0x0C, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xCF, 0x3F, 0xCF, 0x3F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0xF0, 0x0F, 0xF0, 0x0F, 0x30, 0x0C, 0x30, 0x0C, 0xFF, 0xFF, 0xFF, 0xFF, 0x30, 0x0C, 0x30, 0x0C, 0x30, 0x0C, 0x30, 0x0C,
+    0xC0, 0x30, 0xC0, 0x30, 0xFC, 0x3F, 0xFC, 0x3F, 0xC3, 0x30, 0xC3, 0x30, 0x0F, 0x30, 0x0F, 0x30, 0x3C, 0x3C, 0x3C, 0x3C,
+    0x0C, 0x0C, 0x0C, 0x0C, 0xF0, 0x03, 0xF0, 0x03, 0x30, 0x03, 0x30, 0x03, 0xF0, 0x03, 0xF0, 0x03, 0x0C, 0x0C, 0x0C, 0x0C,
+    0xCF, 0x0C, 0xCF, 0x0C, 0xFF, 0x0C, 0xFF, 0x0C, 0xF0, 0xFF, 0xF0, 0xFF, 0xFF, 0x0C, 0xFF, 0x0C, 0xCF, 0x0C, 0xCF, 0x0C,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3F, 0x3F, 0x3F, 0x3F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x3C, 0x3C, 0x3C, 0x3C, 0xC3, 0xC0, 0xC3, 0xC0, 0x33, 0xC3, 0x33, 0xC3, 0xCC, 0x3C, 0xCC, 0x3C,
+    0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0xFC, 0x0F, 0xFC, 0x0F, 0xC3, 0x30, 0xC3, 0x30, 0x33, 0x33, 0x33, 0x33, 0x03, 0x30, 0x03, 0x30, 0xFC, 0x0F, 0xFC, 0x0F,
+    0x3C, 0x0C, 0x3C, 0x0C, 0xC3, 0x0C, 0xC3, 0x0C, 0xC3, 0x0C, 0xC3, 0x0C, 0xFF, 0x0C, 0xFF, 0x0C, 0xC0, 0x0C, 0xC0, 0x0C,
+    0xC0, 0x00, 0xC0, 0x00, 0x30, 0x03, 0x30, 0x03, 0xCC, 0x0C, 0xCC, 0x0C, 0x30, 0x03, 0x30, 0x03, 0x0C, 0x0C, 0x0C, 0x0C,
+    0xC0, 0x00, 0xC0, 0x00, 0xC0, 0x00, 0xC0, 0x00, 0xC0, 0x00, 0xC0, 0x00, 0xC0, 0x00, 0xC0, 0x00, 0xC0, 0x0F, 0xC0, 0x0F,
+    0x00, 0x00, 0x00, 0x00, 0xC0, 0x00, 0xC0, 0x00, 0xC0, 0x00, 0xC0, 0x00, 0xC0, 0x00, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0xFC, 0x0F, 0xFC, 0x0F, 0xF3, 0x33, 0xF3, 0x33, 0x33, 0x30, 0x33, 0x30, 0xC3, 0x33, 0xC3, 0x33, 0xFC, 0x0F, 0xFC, 0x0F,
+    0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x03, 0x00, 0x03, 0x00, 0x03, 0x00, 0x03, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x3C, 0x00, 0x3C, 0x00, 0xFF, 0x00, 0xFF, 0x00, 0xC3, 0x00, 0xC3, 0x00, 0xFF, 0x00, 0xFF, 0x00, 0x3C, 0x00, 0x3C, 0x00,
+    0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0xFF, 0x33, 0xFF, 0x33, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30,
+    0x00, 0x00, 0x00, 0x00, 0xC3, 0x03, 0xC3, 0x03, 0xF3, 0x03, 0xF3, 0x03, 0x3F, 0x03, 0x3F, 0x03, 0x0C, 0x03, 0x0C, 0x03,
+    0x00, 0x00, 0x00, 0x00, 0x03, 0x03, 0x03, 0x03, 0x33, 0x03, 0x33, 0x03, 0xFF, 0x03, 0xFF, 0x03, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x00, 0x0C, 0x00, 0x03, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x30, 0x00, 0x30, 0xFC, 0x3F, 0xFC, 0x3F, 0x00, 0x0C, 0x00, 0x0C, 0xFC, 0x03, 0xFC, 0x03, 0x00, 0x0C, 0x00, 0x0C,
+    0x3C, 0x00, 0x3C, 0x00, 0xC3, 0x00, 0xC3, 0x00, 0xFF, 0x3F, 0xFF, 0x3F, 0x03, 0x00, 0x03, 0x00, 0xFF, 0x3F, 0xFF, 0x3F,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x03, 0x00, 0x03, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0x00, 0xC0, 0x00, 0xC0, 0x00, 0xC0, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x0C, 0x03, 0x0C, 0x03, 0xFF, 0x03, 0xFF, 0x03, 0x00, 0x03, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00,
+    0x3C, 0x0C, 0x3C, 0x0C, 0xC3, 0x0C, 0xC3, 0x0C, 0xC3, 0x0C, 0xC3, 0x0C, 0xC3, 0x0C, 0xC3, 0x0C, 0x3C, 0x0C, 0x3C, 0x0C,
+    0x0C, 0x0C, 0x0C, 0x0C, 0x30, 0x03, 0x30, 0x03, 0xCC, 0x0C, 0xCC, 0x0C, 0x30, 0x03, 0x30, 0x03, 0xC0, 0x00, 0xC0, 0x00,
+    0xFF, 0x0C, 0xFF, 0x0C, 0x00, 0x03, 0x00, 0x03, 0xC0, 0x0C, 0xC0, 0x0C, 0x30, 0x0F, 0x30, 0x0F, 0xCC, 0xFF, 0xCC, 0xFF,
+    0xFF, 0x0C, 0xFF, 0x0C, 0x00, 0x03, 0x00, 0x03, 0xC0, 0xF0, 0xC0, 0xF0, 0xF0, 0xCC, 0xF0, 0xCC, 0xCC, 0xCF, 0xCC, 0xCF,
+    0x33, 0x0C, 0x33, 0x0C, 0x3F, 0x03, 0x3F, 0x03, 0xC0, 0x3C, 0xC0, 0x3C, 0x30, 0xFF, 0x30, 0xFF, 0x0C, 0x30, 0x0C, 0x30,
+    0x00, 0x0F, 0x00, 0x0F, 0xC0, 0x30, 0xC0, 0x30, 0xF3, 0x30, 0xF3, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x0C, 0x00, 0x0C,
+    0x00, 0x3F, 0x00, 0x3F, 0xC3, 0x0C, 0xC3, 0x0C, 0x3C, 0x0C, 0x3C, 0x0C, 0xC0, 0x0C, 0xC0, 0x0C, 0x00, 0x3F, 0x00, 0x3F,
+    0x00, 0x3F, 0x00, 0x3F, 0xC0, 0x0C, 0xC0, 0x0C, 0x3C, 0x0C, 0x3C, 0x0C, 0xC3, 0x0C, 0xC3, 0x0C, 0x00, 0x3F, 0x00, 0x3F,
+    0x00, 0x3F, 0x00, 0x3F, 0xCC, 0x0C, 0xCC, 0x0C, 0x33, 0x0C, 0x33, 0x0C, 0xCC, 0x0C, 0xCC, 0x0C, 0x00, 0x3F, 0x00, 0x3F,
+    0x0C, 0x3F, 0x0C, 0x3F, 0xC3, 0x0C, 0xC3, 0x0C, 0x3F, 0x0C, 0x3F, 0x0C, 0xCC, 0x0C, 0xCC, 0x0C, 0x03, 0x3F, 0x03, 0x3F,
+    0x00, 0xFF, 0x00, 0xFF, 0xC3, 0x0C, 0xC3, 0x0C, 0x30, 0x0C, 0x30, 0x0C, 0xC3, 0x0C, 0xC3, 0x0C, 0x00, 0xFF, 0x00, 0xFF,
+    0x00, 0xFF, 0x00, 0xFF, 0xC0, 0x0C, 0xC0, 0x0C, 0x33, 0x0C, 0x33, 0x0C, 0xC0, 0x0C, 0xC0, 0x0C, 0x00, 0xFF, 0x00, 0xFF,
+    0xF0, 0x3F, 0xF0, 0x3F, 0xCC, 0x00, 0xCC, 0x00, 0xC3, 0x00, 0xC3, 0x00, 0xFF, 0x3F, 0xFF, 0x3F, 0xC3, 0x30, 0xC3, 0x30,
+    0xFC, 0x03, 0xFC, 0x03, 0x03, 0xCC, 0x03, 0xCC, 0x03, 0xCC, 0x03, 0xCC, 0x03, 0x3C, 0x03, 0x3C, 0x0C, 0x03, 0x0C, 0x03,
+    0xF0, 0x3F, 0xF0, 0x3F, 0x33, 0x33, 0x33, 0x33, 0x3C, 0x33, 0x3C, 0x33, 0x30, 0x33, 0x30, 0x33, 0x30, 0x30, 0x30, 0x30,
+    0xF0, 0x3F, 0xF0, 0x3F, 0x30, 0x33, 0x30, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x30, 0x33, 0x30, 0x00, 0x00, 0x00, 0x00,
+    0xF0, 0x3F, 0xF0, 0x3F, 0x3C, 0x33, 0x3C, 0x33, 0x33, 0x33, 0x33, 0x33, 0x3C, 0x33, 0x3C, 0x33, 0x30, 0x30, 0x30, 0x30,
+    0xF0, 0x3F, 0xF0, 0x3F, 0x33, 0x33, 0x33, 0x33, 0x30, 0x33, 0x30, 0x33, 0x33, 0x33, 0x33, 0x33, 0x30, 0x30, 0x30, 0x30,
+    0x00, 0x00, 0x00, 0x00, 0x33, 0x30, 0x33, 0x30, 0xFC, 0x3F, 0xFC, 0x3F, 0x30, 0x30, 0x30, 0x30, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x30, 0x30, 0x30, 0x30, 0xFC, 0x3F, 0xFC, 0x3F, 0x33, 0x30, 0x33, 0x30, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x3C, 0x30, 0x3C, 0x30, 0xF3, 0x3F, 0xF3, 0x3F, 0x3C, 0x30, 0x3C, 0x30, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x33, 0x30, 0x33, 0x30, 0xF0, 0x3F, 0xF0, 0x3F, 0x33, 0x30, 0x33, 0x30, 0x00, 0x00, 0x00, 0x00,
+    0xFF, 0x3F, 0xFF, 0x3F, 0xC3, 0x30, 0xC3, 0x30, 0xC3, 0x30, 0xC3, 0x30, 0x03, 0x30, 0x03, 0x30, 0xFC, 0x0F, 0xFC, 0x0F,
+    0xF3, 0x3F, 0xF3, 0x3F, 0xF3, 0x00, 0xF3, 0x00, 0xC3, 0x03, 0xC3, 0x03, 0x03, 0x0F, 0x03, 0x0F, 0xF3, 0x3F, 0xF3, 0x3F,
+    0xC0, 0x0F, 0xC0, 0x0F, 0x33, 0x30, 0x33, 0x30, 0x3C, 0x30, 0x3C, 0x30, 0x30, 0x30, 0x30, 0x30, 0xC0, 0x0F, 0xC0, 0x0F,
+    0xC0, 0x0F, 0xC0, 0x0F, 0x30, 0x30, 0x30, 0x30, 0x3C, 0x30, 0x3C, 0x30, 0x33, 0x30, 0x33, 0x30, 0xC0, 0x0F, 0xC0, 0x0F,
+    0xC0, 0x0F, 0xC0, 0x0F, 0x3C, 0x30, 0x3C, 0x30, 0x33, 0x30, 0x33, 0x30, 0x3C, 0x30, 0x3C, 0x30, 0xC0, 0x0F, 0xC0, 0x0F,
+    0xCC, 0x0F, 0xCC, 0x0F, 0x33, 0x30, 0x33, 0x30, 0x3F, 0x30, 0x3F, 0x30, 0x3C, 0x30, 0x3C, 0x30, 0xC3, 0x0F, 0xC3, 0x0F,
+    0xC3, 0x0F, 0xC3, 0x0F, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0xC3, 0x0F, 0xC3, 0x0F,
+    0x0C, 0x0C, 0x0C, 0x0C, 0x30, 0x03, 0x30, 0x03, 0xC0, 0x00, 0xC0, 0x00, 0x30, 0x03, 0x30, 0x03, 0x0C, 0x0C, 0x0C, 0x0C,
+    0xFC, 0x3F, 0xFC, 0x3F, 0x03, 0x3C, 0x03, 0x3C, 0xF3, 0x33, 0xF3, 0x33, 0x0F, 0x30, 0x0F, 0x30, 0xFF, 0x0F, 0xFF, 0x0F,
+    0xF0, 0x0F, 0xF0, 0x0F, 0x03, 0x30, 0x03, 0x30, 0x0C, 0x30, 0x0C, 0x30, 0x00, 0x30, 0x00, 0x30, 0xF0, 0x0F, 0xF0, 0x0F,
+    0xF0, 0x0F, 0xF0, 0x0F, 0x00, 0x30, 0x00, 0x30, 0x0C, 0x30, 0x0C, 0x30, 0x03, 0x30, 0x03, 0x30, 0xF0, 0x0F, 0xF0, 0x0F,
+    0xF0, 0x0F, 0xF0, 0x0F, 0x0C, 0x30, 0x0C, 0x30, 0x03, 0x30, 0x03, 0x30, 0x0C, 0x30, 0x0C, 0x30, 0xF0, 0x0F, 0xF0, 0x0F,
+    0xF3, 0x0F, 0xF3, 0x0F, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0xF3, 0x0F, 0xF3, 0x0F,
+    0xF0, 0x00, 0xF0, 0x00, 0x00, 0x03, 0x00, 0x03, 0x0C, 0x3C, 0x0C, 0x3C, 0x03, 0x03, 0x03, 0x03, 0xF0, 0x00, 0xF0, 0x00,
+    0xFF, 0x3F, 0xFF, 0x3F, 0x0C, 0x03, 0x0C, 0x03, 0x0C, 0x03, 0x0C, 0x03, 0x0C, 0x03, 0x0C, 0x03, 0xF0, 0x00, 0xF0, 0x00,
+    0xF0, 0x3F, 0xF0, 0x3F, 0xCC, 0x0C, 0xCC, 0x0C, 0xCC, 0x0C, 0xCC, 0x0C, 0xFC, 0x0F, 0xFC, 0x0F, 0x30, 0x03, 0x30, 0x03,
+    0x03, 0x0C, 0x03, 0x0C, 0x33, 0x33, 0x33, 0x33, 0x30, 0x33, 0x30, 0x33, 0xC0, 0x3F, 0xC0, 0x3F, 0x00, 0x30, 0x00, 0x30,
+    0x00, 0x0C, 0x00, 0x0C, 0x30, 0x33, 0x30, 0x33, 0x30, 0x33, 0x30, 0x33, 0xC3, 0x3F, 0xC3, 0x3F, 0x03, 0x30, 0x03, 0x30,
+    0x03, 0x0C, 0x03, 0x0C, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0xC3, 0x3F, 0xC3, 0x3F, 0x03, 0x30, 0x03, 0x30,
+    0x0C, 0x0C, 0x0C, 0x0C, 0x33, 0x33, 0x33, 0x33, 0x3F, 0x33, 0x3F, 0x33, 0xCC, 0x3F, 0xCC, 0x3F, 0x03, 0x30, 0x03, 0x30,
+    0x03, 0x0C, 0x03, 0x0C, 0x30, 0x33, 0x30, 0x33, 0x30, 0x33, 0x30, 0x33, 0xC0, 0x3F, 0xC0, 0x3F, 0x03, 0x30, 0x03, 0x30,
+    0x00, 0x0C, 0x00, 0x0C, 0x30, 0x33, 0x30, 0x33, 0x33, 0x33, 0x33, 0x33, 0xC3, 0x3F, 0xC3, 0x3F, 0x00, 0x30, 0x00, 0x30,
+    0x00, 0x0C, 0x00, 0x0C, 0x30, 0x33, 0x30, 0x33, 0x30, 0x33, 0x30, 0x33, 0xF0, 0x3F, 0xF0, 0x3F, 0x30, 0x33, 0x30, 0x33,
+    0xF0, 0x00, 0xF0, 0x00, 0xFC, 0x03, 0xFC, 0x03, 0x0C, 0x33, 0x0C, 0x33, 0x0C, 0x3F, 0x0C, 0x3F, 0x0C, 0x03, 0x0C, 0x03,
+    0xC3, 0x0F, 0xC3, 0x0F, 0x33, 0x33, 0x33, 0x33, 0x30, 0x33, 0x30, 0x33, 0x30, 0x33, 0x30, 0x33, 0xC0, 0x33, 0xC0, 0x33,
+    0xC0, 0x0F, 0xC0, 0x0F, 0x30, 0x33, 0x30, 0x33, 0x30, 0x33, 0x30, 0x33, 0x33, 0x33, 0x33, 0x33, 0xC3, 0x33, 0xC3, 0x33,
+    0xC3, 0x0F, 0xC3, 0x0F, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0xC3, 0x33, 0xC3, 0x33,
+    0xC3, 0x0F, 0xC3, 0x0F, 0x30, 0x33, 0x30, 0x33, 0x30, 0x33, 0x30, 0x33, 0x30, 0x33, 0x30, 0x33, 0xC3, 0x33, 0xC3, 0x33,
+    0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x03, 0x00, 0x33, 0x30, 0x33, 0x30, 0xF0, 0x3F, 0xF0, 0x3F, 0x00, 0x30, 0x00, 0x30,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x30, 0x30, 0x30, 0xF3, 0x3F, 0xF3, 0x3F, 0x03, 0x30, 0x03, 0x30,
+    0x00, 0x00, 0x00, 0x00, 0x0C, 0x00, 0x0C, 0x00, 0x33, 0x30, 0x33, 0x30, 0xF3, 0x3F, 0xF3, 0x3F, 0x0C, 0x30, 0x0C, 0x30,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x33, 0x30, 0x33, 0x30, 0xF0, 0x3F, 0xF0, 0x3F, 0x03, 0x30, 0x03, 0x30,
+    0x00, 0x0C, 0x00, 0x0C, 0x33, 0x33, 0x33, 0x33, 0x0C, 0x33, 0x0C, 0x33, 0x33, 0x33, 0x33, 0x33, 0xC0, 0x0F, 0xC0, 0x0F,
+    0x00, 0x00, 0x00, 0x00, 0xCC, 0x3F, 0xCC, 0x3F, 0xCC, 0x00, 0xCC, 0x00, 0xCC, 0x00, 0xCC, 0x00, 0x0C, 0x3F, 0x0C, 0x3F,
+    0x0C, 0x0F, 0x0C, 0x0F, 0xCC, 0x30, 0xCC, 0x30, 0xC0, 0x30, 0xC0, 0x30, 0xC0, 0x30, 0xC0, 0x30, 0x00, 0x0F, 0x00, 0x0F,
+    0x00, 0x0F, 0x00, 0x0F, 0xC0, 0x30, 0xC0, 0x30, 0xC0, 0x30, 0xC0, 0x30, 0xCC, 0x30, 0xCC, 0x30, 0x0C, 0x0F, 0x0C, 0x0F,
+    0x0C, 0x0F, 0x0C, 0x0F, 0xC3, 0x30, 0xC3, 0x30, 0xC3, 0x30, 0xC3, 0x30, 0xC3, 0x30, 0xC3, 0x30, 0x0C, 0x0F, 0x0C, 0x0F,
+    0xCC, 0x0F, 0xCC, 0x0F, 0x33, 0x30, 0x33, 0x30, 0x3F, 0x30, 0x3F, 0x30, 0x3C, 0x30, 0x3C, 0x30, 0xC3, 0x0F, 0xC3, 0x0F,
+    0x0C, 0x0F, 0x0C, 0x0F, 0xC0, 0x30, 0xC0, 0x30, 0xC0, 0x30, 0xC0, 0x30, 0xC0, 0x30, 0xC0, 0x30, 0x0C, 0x0F, 0x0C, 0x0F,
+    0xC0, 0x00, 0xC0, 0x00, 0xC0, 0x00, 0xC0, 0x00, 0xCF, 0x3C, 0xCF, 0x3C, 0xCF, 0x3C, 0xCF, 0x3C, 0xC0, 0x00, 0xC0, 0x00,
+    0xC0, 0x3F, 0xC0, 0x3F, 0x30, 0x3C, 0x30, 0x3C, 0x30, 0x33, 0x30, 0x33, 0xF0, 0x30, 0xF0, 0x30, 0xF0, 0x0F, 0xF0, 0x0F,
+    0xCC, 0x0F, 0xCC, 0x0F, 0x0C, 0x30, 0x0C, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x0C, 0x00, 0x0C, 0xC0, 0x3F, 0xC0, 0x3F,
+    0xC0, 0x0F, 0xC0, 0x0F, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x0C, 0x0C, 0x0C, 0x0C, 0xCC, 0x3F, 0xCC, 0x3F,
+    0xCC, 0x0F, 0xCC, 0x0F, 0x03, 0x30, 0x03, 0x30, 0x03, 0x30, 0x03, 0x30, 0x03, 0x0C, 0x03, 0x0C, 0xCC, 0x3F, 0xCC, 0x3F,
+    0xCC, 0x0F, 0xCC, 0x0F, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x0C, 0x00, 0x0C, 0xCC, 0x3F, 0xCC, 0x3F,
+    0xF0, 0x30, 0xF0, 0x30, 0x00, 0xC3, 0x00, 0xC3, 0x0C, 0xC3, 0x0C, 0xC3, 0x03, 0xC3, 0x03, 0xC3, 0xF0, 0x3F, 0xF0, 0x3F,
+    0xFC, 0xFF, 0xFC, 0xFF, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0xC0, 0x0F, 0xC0, 0x0F,
+    0x00, 0x00, 0x00, 0x00, 0xF3, 0xC3, 0xF3, 0xC3, 0x00, 0xCC, 0x00, 0xCC, 0x00, 0xCC, 0x00, 0xCC, 0xF3, 0x3F, 0xF3, 0x3F,
+    0x00, 0x3F, 0x00, 0x3F, 0xC3, 0x0C, 0xC3, 0x0C, 0x33, 0x0C, 0x33, 0x0C, 0xC3, 0x0C, 0xC3, 0x0C, 0x00, 0x3F, 0x00, 0x3F,
+    0x00, 0x0C, 0x00, 0x0C, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0xC3, 0x3F, 0xC3, 0x3F, 0x00, 0x30, 0x00, 0x30,
+    0x03, 0x3F, 0x03, 0x3F, 0xCC, 0x0C, 0xCC, 0x0C, 0x3C, 0x0C, 0x3C, 0x0C, 0xCC, 0x0C, 0xCC, 0x0C, 0x03, 0x3F, 0x03, 0x3F,
+    0x03, 0x0C, 0x03, 0x0C, 0x3C, 0x33, 0x3C, 0x33, 0x3C, 0x33, 0x3C, 0x33, 0xCC, 0x3F, 0xCC, 0x3F, 0x03, 0x30, 0x03, 0x30,
+    0x00, 0x3F, 0x00, 0x3F, 0xC0, 0x0C, 0xC0, 0x0C, 0x30, 0x0C, 0x30, 0x0C, 0xC0, 0xCC, 0xC0, 0xCC, 0x00, 0xFF, 0x00, 0xFF,
+    0x00, 0x0C, 0x00, 0x0C, 0x30, 0x33, 0x30, 0x33, 0x30, 0x33, 0x30, 0x33, 0xC0, 0xFF, 0xC0, 0xFF, 0x00, 0xF0, 0x00, 0xF0,
+    0xC0, 0x0F, 0xC0, 0x0F, 0x30, 0x30, 0x30, 0x30, 0x3C, 0x30, 0x3C, 0x30, 0x33, 0x30, 0x33, 0x30, 0xC0, 0x0C, 0xC0, 0x0C,
+    0xC0, 0x0F, 0xC0, 0x0F, 0x30, 0x30, 0x30, 0x30, 0x3C, 0x30, 0x3C, 0x30, 0x33, 0x30, 0x33, 0x30, 0xC0, 0x0C, 0xC0, 0x0C,
+    0xC0, 0x0F, 0xC0, 0x0F, 0x3C, 0x30, 0x3C, 0x30, 0x33, 0x30, 0x33, 0x30, 0x3C, 0x30, 0x3C, 0x30, 0xC0, 0x0C, 0xC0, 0x0C,
+    0xC0, 0x0F, 0xC0, 0x0F, 0x3C, 0x30, 0x3C, 0x30, 0x33, 0x30, 0x33, 0x30, 0x3C, 0x30, 0x3C, 0x30, 0xC0, 0x0C, 0xC0, 0x0C,
+    0xC0, 0x0F, 0xC0, 0x0F, 0x30, 0x30, 0x30, 0x30, 0x33, 0x30, 0x33, 0x30, 0x30, 0x30, 0x30, 0x30, 0xC0, 0x0C, 0xC0, 0x0C,
+    0xC0, 0x0F, 0xC0, 0x0F, 0x30, 0x30, 0x30, 0x30, 0x33, 0x30, 0x33, 0x30, 0x30, 0x30, 0x30, 0x30, 0xC0, 0x0C, 0xC0, 0x0C,
+    0xC0, 0x0F, 0xC0, 0x0F, 0x33, 0x30, 0x33, 0x30, 0x3C, 0x30, 0x3C, 0x30, 0x33, 0x30, 0x33, 0x30, 0xC0, 0x0C, 0xC0, 0x0C,
+    0xC0, 0x0F, 0xC0, 0x0F, 0x33, 0x30, 0x33, 0x30, 0x3C, 0x30, 0x3C, 0x30, 0x33, 0x30, 0x33, 0x30, 0xC0, 0x0C, 0xC0, 0x0C,
+    0xF0, 0x3F, 0xF0, 0x3F, 0x33, 0x30, 0x33, 0x30, 0x3C, 0x30, 0x3C, 0x30, 0x33, 0x30, 0x33, 0x30, 0xC0, 0x0F, 0xC0, 0x0F,
+    0xC0, 0x0F, 0xC0, 0x0F, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0xC0, 0x0C, 0xC0, 0x0C, 0xFF, 0x3F, 0xFF, 0x3F,
+    0xFF, 0x3F, 0xFF, 0x3F, 0xC3, 0x30, 0xC3, 0x30, 0xC3, 0x30, 0xC3, 0x30, 0x03, 0x30, 0x03, 0x30, 0xFC, 0x0F, 0xFC, 0x0F,
+    0xC0, 0x0F, 0xC0, 0x0F, 0x30, 0x30, 0x30, 0x30, 0x3C, 0x30, 0x3C, 0x30, 0xCF, 0x0C, 0xCF, 0x0C, 0xFC, 0x3F, 0xFC, 0x3F,
+    0xF0, 0x3F, 0xF0, 0x3F, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x30, 0x30, 0x30, 0x30,
+    0xC0, 0x0F, 0xC0, 0x0F, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0xC0, 0x03, 0xC0, 0x03,
+    0xF3, 0x3F, 0xF3, 0x3F, 0x3C, 0x33, 0x3C, 0x33, 0x3C, 0x33, 0x3C, 0x33, 0x3C, 0x33, 0x3C, 0x33, 0x33, 0x30, 0x33, 0x30,
+    0xC3, 0x0F, 0xC3, 0x0F, 0x3C, 0x33, 0x3C, 0x33, 0x3C, 0x33, 0x3C, 0x33, 0x3C, 0x33, 0x3C, 0x33, 0xC3, 0x03, 0xC3, 0x03,
+    0xF0, 0x3F, 0xF0, 0x3F, 0x30, 0x33, 0x30, 0x33, 0x33, 0x33, 0x33, 0x33, 0x30, 0x33, 0x30, 0x33, 0x30, 0x30, 0x30, 0x30,
+    0xC0, 0x0F, 0xC0, 0x0F, 0x30, 0x33, 0x30, 0x33, 0x33, 0x33, 0x33, 0x33, 0x30, 0x33, 0x30, 0x33, 0xC0, 0x03, 0xC0, 0x03,
+    0xF0, 0x3F, 0xF0, 0x3F, 0x30, 0x33, 0x30, 0x33, 0x30, 0x33, 0x30, 0x33, 0x30, 0xF3, 0x30, 0xF3, 0x30, 0xF0, 0x30, 0xF0,
+    0xC0, 0x0F, 0xC0, 0x0F, 0x30, 0x33, 0x30, 0x33, 0x30, 0x33, 0x30, 0x33, 0x30, 0xF3, 0x30, 0xF3, 0xC0, 0xC3, 0xC0, 0xC3,
+    0xF0, 0x3F, 0xF0, 0x3F, 0x33, 0x33, 0x33, 0x33, 0x3C, 0x33, 0x3C, 0x33, 0x33, 0x33, 0x33, 0x33, 0x30, 0x30, 0x30, 0x30,
+    0xC0, 0x0F, 0xC0, 0x0F, 0x33, 0x33, 0x33, 0x33, 0x3C, 0x33, 0x3C, 0x33, 0x33, 0x33, 0x33, 0x33, 0xC0, 0x03, 0xC0, 0x03,
+    0xC0, 0x0F, 0xC0, 0x0F, 0x3C, 0x30, 0x3C, 0x30, 0x33, 0x30, 0x33, 0x30, 0x3C, 0x3C, 0x3C, 0x3C, 0xF0, 0x3C, 0xF0, 0x3C,
+    0xC0, 0x03, 0xC0, 0x03, 0x3C, 0xCC, 0x3C, 0xCC, 0x33, 0xCC, 0x33, 0xCC, 0xFC, 0xC3, 0xFC, 0xC3, 0xC0, 0x3F, 0xC0, 0x3F,
+    0xC3, 0x0F, 0xC3, 0x0F, 0x3C, 0x30, 0x3C, 0x30, 0x3C, 0x30, 0x3C, 0x30, 0x3C, 0x3C, 0x3C, 0x3C, 0xF3, 0x3C, 0xF3, 0x3C,
+    0xC3, 0x03, 0xC3, 0x03, 0x3C, 0xCC, 0x3C, 0xCC, 0x3C, 0xCC, 0x3C, 0xCC, 0xFC, 0xC3, 0xFC, 0xC3, 0xC3, 0x3F, 0xC3, 0x3F,
+    0xC0, 0x0F, 0xC0, 0x0F, 0x30, 0x30, 0x30, 0x30, 0x33, 0x30, 0x33, 0x30, 0x30, 0x3C, 0x30, 0x3C, 0xF0, 0x3C, 0xF0, 0x3C,
+    0xC0, 0x03, 0xC0, 0x03, 0x30, 0xCC, 0x30, 0xCC, 0x33, 0xCC, 0x33, 0xCC, 0xF0, 0xC3, 0xF0, 0xC3, 0xC0, 0x3F, 0xC0, 0x3F,
+    0xC0, 0x0F, 0xC0, 0x0F, 0x30, 0x30, 0x30, 0x30, 0x30, 0xF0, 0x30, 0xF0, 0x30, 0xFC, 0x30, 0xFC, 0xF0, 0x3C, 0xF0, 0x3C,
+    0xC0, 0x03, 0xC0, 0x03, 0x30, 0xCC, 0x30, 0xCC, 0x3C, 0xCC, 0x3C, 0xCC, 0xF3, 0xC3, 0xF3, 0xC3, 0xC0, 0x3F, 0xC0, 0x3F,
+    0xF0, 0x3F, 0xF0, 0x3F, 0x0C, 0x03, 0x0C, 0x03, 0x03, 0x03, 0x03, 0x03, 0x0C, 0x03, 0x0C, 0x03, 0xF0, 0x3F, 0xF0, 0x3F,
+    0xFF, 0x3F, 0xFF, 0x3F, 0xC0, 0x00, 0xC0, 0x00, 0x3C, 0x00, 0x3C, 0x00, 0x33, 0x00, 0x33, 0x00, 0xCC, 0x3F, 0xCC, 0x3F,
+    0xFF, 0x3F, 0xFF, 0x3F, 0xCC, 0x00, 0xCC, 0x00, 0xCC, 0x00, 0xCC, 0x00, 0xCC, 0x00, 0xCC, 0x00, 0xFF, 0x3F, 0xFF, 0x3F,
+    0x0C, 0x00, 0x0C, 0x00, 0xFF, 0x3F, 0xFF, 0x3F, 0xCC, 0x00, 0xCC, 0x00, 0x30, 0x00, 0x30, 0x00, 0xC0, 0x3F, 0xC0, 0x3F,
+    0x0C, 0x00, 0x0C, 0x00, 0x33, 0x30, 0x33, 0x30, 0xFF, 0x3F, 0xFF, 0x3F, 0x3C, 0x30, 0x3C, 0x30, 0x03, 0x00, 0x03, 0x00,
+    0x0C, 0x00, 0x0C, 0x00, 0x33, 0x30, 0x33, 0x30, 0xFF, 0x3F, 0xFF, 0x3F, 0x0C, 0x30, 0x0C, 0x30, 0x03, 0x00, 0x03, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x33, 0x30, 0x33, 0x30, 0xF3, 0x3F, 0xF3, 0x3F, 0x33, 0x30, 0x33, 0x30, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x33, 0x30, 0x33, 0x30, 0xF3, 0x3F, 0xF3, 0x3F, 0x03, 0x30, 0x03, 0x30, 0x00, 0x00, 0x00, 0x00,
+    0x03, 0x00, 0x03, 0x00, 0x3C, 0x30, 0x3C, 0x30, 0xFC, 0x3F, 0xFC, 0x3F, 0x3C, 0x30, 0x3C, 0x30, 0x03, 0x00, 0x03, 0x00,
+    0x03, 0x00, 0x03, 0x00, 0x3C, 0x30, 0x3C, 0x30, 0xFC, 0x3F, 0xFC, 0x3F, 0x0C, 0x30, 0x0C, 0x30, 0x03, 0x00, 0x03, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x30, 0x30, 0x30, 0x30, 0xF0, 0x3F, 0xF0, 0x3F, 0x30, 0xF0, 0x30, 0xF0, 0x00, 0xC0, 0x00, 0xC0,
+    0x00, 0x00, 0x00, 0x00, 0x30, 0x30, 0x30, 0x30, 0xF0, 0x3F, 0xF0, 0x3F, 0x00, 0xF0, 0x00, 0xF0, 0x00, 0xC0, 0x00, 0xC0,
+    0x00, 0x00, 0x00, 0x00, 0x30, 0x30, 0x30, 0x30, 0xF3, 0x3F, 0xF3, 0x3F, 0x30, 0x30, 0x30, 0x30, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x30, 0x30, 0x30, 0x30, 0xF0, 0x3F, 0xF0, 0x3F, 0x00, 0x30, 0x00, 0x30, 0x00, 0x00, 0x00, 0x00,
+    0xFF, 0x3F, 0xFF, 0x3F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x00, 0x0C, 0x03, 0x30, 0x03, 0x30, 0xFF, 0x0F, 0xFF, 0x0F,
+    0xF3, 0x3F, 0xF3, 0x3F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x00, 0x30, 0x30, 0xC0, 0x30, 0xC0, 0xF3, 0x3F, 0xF3, 0x3F,
+    0x00, 0x0C, 0x00, 0x0C, 0x0C, 0x30, 0x0C, 0x30, 0x33, 0x30, 0x33, 0x30, 0xFC, 0x0F, 0xFC, 0x0F, 0x30, 0x00, 0x30, 0x00,
+    0x00, 0x0C, 0x00, 0x0C, 0x0C, 0x30, 0x0C, 0x30, 0x03, 0x30, 0x03, 0x30, 0xFC, 0x0F, 0xFC, 0x0F, 0x00, 0x00, 0x00, 0x00,
+    0xF0, 0x3F, 0xF0, 0x3F, 0x00, 0x03, 0x00, 0x03, 0xC0, 0xCC, 0xC0, 0xCC, 0x00, 0xC0, 0x00, 0xC0, 0x30, 0x30, 0x30, 0x30,
+    0xFF, 0x3F, 0xFF, 0x3F, 0x00, 0x03, 0x00, 0x03, 0xC0, 0xCC, 0xC0, 0xCC, 0x30, 0xF0, 0x30, 0xF0, 0x00, 0x00, 0x00, 0x00,
+    0xF0, 0x3F, 0xF0, 0x3F, 0x00, 0x03, 0x00, 0x03, 0xC0, 0x0C, 0xC0, 0x0C, 0x30, 0x30, 0x30, 0x30, 0x00, 0x00, 0x00, 0x00,
+    0xF0, 0x3F, 0xF0, 0x3F, 0x00, 0x30, 0x00, 0x30, 0x0C, 0x30, 0x0C, 0x30, 0x03, 0x30, 0x03, 0x30, 0x00, 0x30, 0x00, 0x30,
+    0x00, 0x00, 0x00, 0x00, 0x03, 0x30, 0x03, 0x30, 0xFF, 0x3F, 0xFF, 0x3F, 0x0C, 0x30, 0x0C, 0x30, 0x03, 0x00, 0x03, 0x00,
+    0xF0, 0x3F, 0xF0, 0x3F, 0x00, 0x30, 0x00, 0x30, 0x00, 0xF0, 0x00, 0xF0, 0x00, 0xF0, 0x00, 0xF0, 0x00, 0x30, 0x00, 0x30,
+    0x00, 0x00, 0x00, 0x00, 0x03, 0x30, 0x03, 0x30, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0xF0, 0x00, 0xF0, 0x00, 0x00, 0x00, 0x00,
+    0xF0, 0x3F, 0xF0, 0x3F, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x0F, 0x30, 0x0F, 0x30,
+    0x00, 0x00, 0x00, 0x00, 0x03, 0x30, 0x03, 0x30, 0xFF, 0x3F, 0xFF, 0x3F, 0x00, 0x30, 0x00, 0x30, 0x0F, 0x00, 0x0F, 0x00,
+    0xFF, 0x3F, 0xFF, 0x3F, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0xC0, 0x30, 0xC0, 0x30, 0x00, 0x30, 0x00, 0x30,
+    0x00, 0x00, 0x00, 0x00, 0x03, 0x30, 0x03, 0x30, 0xFF, 0x3F, 0xFF, 0x3F, 0x00, 0x30, 0x00, 0x30, 0xC0, 0x00, 0xC0, 0x00,
+    0x00, 0x03, 0x00, 0x03, 0xFF, 0x3F, 0xFF, 0x3F, 0xC0, 0x30, 0xC0, 0x30, 0x30, 0x30, 0x30, 0x30, 0x00, 0x30, 0x00, 0x30,
+    0x00, 0x0C, 0x00, 0x0C, 0x03, 0x33, 0x03, 0x33, 0xFF, 0x3F, 0xFF, 0x3F, 0xC0, 0x30, 0xC0, 0x30, 0x30, 0x00, 0x30, 0x00,
+    0xF0, 0x3F, 0xF0, 0x3F, 0xC0, 0x00, 0xC0, 0x00, 0x0C, 0x03, 0x0C, 0x03, 0x03, 0x0C, 0x03, 0x0C, 0xF0, 0x3F, 0xF0, 0x3F,
+    0xF0, 0x3F, 0xF0, 0x3F, 0xC0, 0x00, 0xC0, 0x00, 0x3C, 0x00, 0x3C, 0x00, 0x33, 0x00, 0x33, 0x00, 0xC0, 0x3F, 0xC0, 0x3F,
+    0xF0, 0x3F, 0xF0, 0x3F, 0xC0, 0x00, 0xC0, 0x00, 0x00, 0xC3, 0x00, 0xC3, 0x00, 0xCC, 0x00, 0xCC, 0xF0, 0x3F, 0xF0, 0x3F,
+    0xF0, 0x3F, 0xF0, 0x3F, 0xC0, 0x00, 0xC0, 0x00, 0x30, 0xC0, 0x30, 0xC0, 0x30, 0xC0, 0x30, 0xC0, 0xC0, 0x3F, 0xC0, 0x3F,
+    0xF0, 0x3F, 0xF0, 0x3F, 0xC3, 0x00, 0xC3, 0x00, 0x0C, 0x03, 0x0C, 0x03, 0x03, 0x0C, 0x03, 0x0C, 0xF0, 0x3F, 0xF0, 0x3F,
+    0xF0, 0x3F, 0xF0, 0x3F, 0xC3, 0x00, 0xC3, 0x00, 0x3C, 0x00, 0x3C, 0x00, 0x33, 0x00, 0x33, 0x00, 0xC0, 0x3F, 0xC0, 0x3F,
+    0x0F, 0x00, 0x0F, 0x00, 0xC0, 0x3F, 0xC0, 0x3F, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0xC0, 0x3F, 0xC0, 0x3F,
+    0xFF, 0x3F, 0xFF, 0x3F, 0x0C, 0x00, 0x0C, 0x00, 0x30, 0xC0, 0x30, 0xC0, 0xC0, 0xC0, 0xC0, 0xC0, 0xFF, 0x3F, 0xFF, 0x3F,
+    0xF0, 0x3F, 0xF0, 0x3F, 0xC0, 0x00, 0xC0, 0x00, 0x30, 0xC0, 0x30, 0xC0, 0x30, 0xC0, 0x30, 0xC0, 0xC0, 0x3F, 0xC0, 0x3F,
+    0xC0, 0x0F, 0xC0, 0x0F, 0x33, 0x30, 0x33, 0x30, 0x33, 0x30, 0x33, 0x30, 0x33, 0x30, 0x33, 0x30, 0xC0, 0x0F, 0xC0, 0x0F,
+    0xC0, 0x0F, 0xC0, 0x0F, 0x33, 0x30, 0x33, 0x30, 0x33, 0x30, 0x33, 0x30, 0x33, 0x30, 0x33, 0x30, 0xC0, 0x0F, 0xC0, 0x0F,
+    0xC3, 0x0F, 0xC3, 0x0F, 0x3C, 0x30, 0x3C, 0x30, 0x3C, 0x30, 0x3C, 0x30, 0x3C, 0x30, 0x3C, 0x30, 0xC3, 0x0F, 0xC3, 0x0F,
+    0xC3, 0x0F, 0xC3, 0x0F, 0x3C, 0x30, 0x3C, 0x30, 0x3C, 0x30, 0x3C, 0x30, 0x3C, 0x30, 0x3C, 0x30, 0xC3, 0x0F, 0xC3, 0x0F,
+    0xC0, 0x0F, 0xC0, 0x0F, 0x3C, 0x30, 0x3C, 0x30, 0x33, 0x30, 0x33, 0x30, 0x3C, 0x30, 0x3C, 0x30, 0xC3, 0x0F, 0xC3, 0x0F,
+    0xC0, 0x0F, 0xC0, 0x0F, 0x3C, 0x30, 0x3C, 0x30, 0x33, 0x30, 0x33, 0x30, 0x3C, 0x30, 0x3C, 0x30, 0xC3, 0x0F, 0xC3, 0x0F,
+    0xFC, 0x0F, 0xFC, 0x0F, 0x03, 0x30, 0x03, 0x30, 0xFF, 0x3F, 0xFF, 0x3F, 0xC3, 0x30, 0xC3, 0x30, 0xC3, 0x30, 0xC3, 0x30,
+    0xC0, 0x0F, 0xC0, 0x0F, 0x30, 0x30, 0x30, 0x30, 0xC0, 0x0F, 0xC0, 0x0F, 0x30, 0x33, 0x30, 0x33, 0xC0, 0x33, 0xC0, 0x33,
+    0xF0, 0x3F, 0xF0, 0x3F, 0x30, 0x03, 0x30, 0x03, 0x3C, 0x0F, 0x3C, 0x0F, 0x33, 0x03, 0x33, 0x03, 0xC0, 0x30, 0xC0, 0x30,
+    0xF0, 0x3F, 0xF0, 0x3F, 0xC0, 0x00, 0xC0, 0x00, 0x3C, 0x00, 0x3C, 0x00, 0x33, 0x00, 0x33, 0x00, 0xC0, 0x00, 0xC0, 0x00,
+    0xF0, 0x3F, 0xF0, 0x3F, 0x30, 0x03, 0x30, 0x03, 0x30, 0xCF, 0x30, 0xCF, 0x30, 0xC3, 0x30, 0xC3, 0xC0, 0x30, 0xC0, 0x30,
+    0xF0, 0x3F, 0xF0, 0x3F, 0xC0, 0x00, 0xC0, 0x00, 0x30, 0xC0, 0x30, 0xC0, 0x30, 0xC0, 0x30, 0xC0, 0xC0, 0x00, 0xC0, 0x00,
+    0xF0, 0x3F, 0xF0, 0x3F, 0x33, 0x03, 0x33, 0x03, 0x3C, 0x0F, 0x3C, 0x0F, 0x33, 0x03, 0x33, 0x03, 0xC0, 0x30, 0xC0, 0x30,
+    0xF0, 0x3F, 0xF0, 0x3F, 0xC3, 0x00, 0xC3, 0x00, 0x3C, 0x00, 0x3C, 0x00, 0x33, 0x00, 0x33, 0x00, 0xC0, 0x00, 0xC0, 0x00,
+    0xC0, 0x00, 0xC0, 0x00, 0x30, 0x33, 0x30, 0x33, 0x3C, 0x33, 0x3C, 0x33, 0x33, 0x33, 0x33, 0x33, 0x00, 0x0C, 0x00, 0x0C,
+    0xC0, 0x30, 0xC0, 0x30, 0x30, 0x33, 0x30, 0x33, 0x3C, 0x33, 0x3C, 0x33, 0x33, 0x33, 0x33, 0x33, 0x30, 0x0C, 0x30, 0x0C,
+    0xC0, 0x00, 0xC0, 0x00, 0x3C, 0x33, 0x3C, 0x33, 0x33, 0x33, 0x33, 0x33, 0x3C, 0x33, 0x3C, 0x33, 0x00, 0x0C, 0x00, 0x0C,
+    0xC0, 0x30, 0xC0, 0x30, 0x3C, 0x33, 0x3C, 0x33, 0x33, 0x33, 0x33, 0x33, 0x3C, 0x33, 0x3C, 0x33, 0x30, 0x0C, 0x30, 0x0C,
+    0xC0, 0x00, 0xC0, 0x00, 0x30, 0x33, 0x30, 0x33, 0x30, 0xF3, 0x30, 0xF3, 0x30, 0xF3, 0x30, 0xF3, 0x00, 0x0C, 0x00, 0x0C,
+    0xC0, 0x30, 0xC0, 0x30, 0x30, 0x33, 0x30, 0x33, 0x30, 0xF3, 0x30, 0xF3, 0x30, 0xF3, 0x30, 0xF3, 0x30, 0x0C, 0x30, 0x0C,
+    0xC0, 0x00, 0xC0, 0x00, 0x33, 0x33, 0x33, 0x33, 0x3C, 0x33, 0x3C, 0x33, 0x33, 0x33, 0x33, 0x33, 0x00, 0x0C, 0x00, 0x0C,
+    0xC0, 0x30, 0xC0, 0x30, 0x33, 0x33, 0x33, 0x33, 0x3C, 0x33, 0x3C, 0x33, 0x33, 0x33, 0x33, 0x33, 0x30, 0x0C, 0x30, 0x0C,
+    0xF0, 0x00, 0xF0, 0x00, 0x30, 0x00, 0x30, 0x00, 0xF0, 0xFF, 0xF0, 0xFF, 0x30, 0xC0, 0x30, 0xC0, 0xF0, 0x00, 0xF0, 0x00,
+    0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0xFF, 0xCF, 0xFF, 0xCF, 0x30, 0xF0, 0x30, 0xF0, 0x30, 0x0C, 0x30, 0x0C,
+    0xF0, 0x00, 0xF0, 0x00, 0x33, 0x00, 0x33, 0x00, 0xFC, 0x3F, 0xFC, 0x3F, 0x33, 0x00, 0x33, 0x00, 0xF0, 0x00, 0xF0, 0x00,
+    0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0xFF, 0x0F, 0xFF, 0x0F, 0x30, 0x30, 0x30, 0x30, 0x3F, 0x0C, 0x3F, 0x0C,
+    0x03, 0x00, 0x03, 0x00, 0xC3, 0x00, 0xC3, 0x00, 0xFF, 0x3F, 0xFF, 0x3F, 0xC3, 0x00, 0xC3, 0x00, 0x03, 0x00, 0x03, 0x00,
+    0x30, 0x00, 0x30, 0x00, 0x30, 0x03, 0x30, 0x03, 0xFF, 0x0F, 0xFF, 0x0F, 0x30, 0x33, 0x30, 0x33, 0x30, 0x0C, 0x30, 0x0C,
+    0xFC, 0x0F, 0xFC, 0x0F, 0x03, 0x30, 0x03, 0x30, 0x0F, 0x30, 0x0F, 0x30, 0x0C, 0x30, 0x0C, 0x30, 0xF3, 0x0F, 0xF3, 0x0F,
+    0xFC, 0x0F, 0xFC, 0x0F, 0x03, 0x30, 0x03, 0x30, 0x0F, 0x30, 0x0F, 0x30, 0x0C, 0x0C, 0x0C, 0x0C, 0xF3, 0x3F, 0xF3, 0x3F,
+    0xF0, 0x0F, 0xF0, 0x0F, 0x03, 0x30, 0x03, 0x30, 0x03, 0x30, 0x03, 0x30, 0x03, 0x30, 0x03, 0x30, 0xF0, 0x0F, 0xF0, 0x0F,
+    0xF0, 0x0F, 0xF0, 0x0F, 0x03, 0x30, 0x03, 0x30, 0x03, 0x30, 0x03, 0x30, 0x03, 0x0C, 0x03, 0x0C, 0xF0, 0x3F, 0xF0, 0x3F,
+    0xF3, 0x0F, 0xF3, 0x0F, 0x0C, 0x30, 0x0C, 0x30, 0x0C, 0x30, 0x0C, 0x30, 0x0C, 0x30, 0x0C, 0x30, 0xF3, 0x0F, 0xF3, 0x0F,
+    0xF3, 0x0F, 0xF3, 0x0F, 0x0C, 0x30, 0x0C, 0x30, 0x0C, 0x30, 0x0C, 0x30, 0x0C, 0x0C, 0x0C, 0x0C, 0xF3, 0x3F, 0xF3, 0x3F,
+    0xF0, 0x0F, 0xF0, 0x0F, 0x0F, 0x30, 0x0F, 0x30, 0x03, 0x30, 0x03, 0x30, 0x0F, 0x30, 0x0F, 0x30, 0xF0, 0x0F, 0xF0, 0x0F,
+    0xF0, 0x0F, 0xF0, 0x0F, 0x0F, 0x30, 0x0F, 0x30, 0x03, 0x30, 0x03, 0x30, 0x0F, 0x0C, 0x0F, 0x0C, 0xF0, 0x3F, 0xF0, 0x3F,
+    0xF0, 0x0F, 0xF0, 0x0F, 0x0C, 0x30, 0x0C, 0x30, 0x03, 0x30, 0x03, 0x30, 0x0C, 0x30, 0x0C, 0x30, 0xF3, 0x0F, 0xF3, 0x0F,
+    0xF0, 0x0F, 0xF0, 0x0F, 0x0C, 0x30, 0x0C, 0x30, 0x03, 0x30, 0x03, 0x30, 0x0C, 0x0C, 0x0C, 0x0C, 0xF3, 0x3F, 0xF3, 0x3F,
+    0xF0, 0x0F, 0xF0, 0x0F, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0xF0, 0x00, 0xF0, 0xF0, 0xCF, 0xF0, 0xCF,
+    0xF0, 0x0F, 0xF0, 0x0F, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0x30, 0x00, 0xCC, 0x00, 0xCC, 0xF0, 0xFF, 0xF0, 0xFF,
+    0xF0, 0x0F, 0xF0, 0x0F, 0x0C, 0x30, 0x0C, 0x30, 0x03, 0x0F, 0x03, 0x0F, 0x0C, 0x30, 0x0C, 0x30, 0xF0, 0x0F, 0xF0, 0x0F,
+    0xF0, 0x0F, 0xF0, 0x0F, 0x0C, 0x30, 0x0C, 0x30, 0x03, 0x0F, 0x03, 0x0F, 0x0C, 0x30, 0x0C, 0x30, 0xF0, 0x0F, 0xF0, 0x0F,
+    0xF0, 0x00, 0xF0, 0x00, 0x0C, 0x03, 0x0C, 0x03, 0x03, 0x3C, 0x03, 0x3C, 0x0C, 0x03, 0x0C, 0x03, 0xF0, 0x00, 0xF0, 0x00,
+    0xF0, 0x30, 0xF0, 0x30, 0x0C, 0xC3, 0x0C, 0xC3, 0x03, 0xC3, 0x03, 0xC3, 0x0C, 0xC3, 0x0C, 0xC3, 0xF0, 0x3F, 0xF0, 0x3F,
+    0xF0, 0x00, 0xF0, 0x00, 0x03, 0x03, 0x03, 0x03, 0x00, 0x3C, 0x00, 0x3C, 0x03, 0x03, 0x03, 0x03, 0xF0, 0x00, 0xF0, 0x00,
+    0x30, 0x30, 0x30, 0x30, 0x30, 0x3F, 0x30, 0x3F, 0x3C, 0x33, 0x3C, 0x33, 0xF3, 0x33, 0xF3, 0x33, 0x30, 0x30, 0x30, 0x30,
+    0x30, 0x30, 0x30, 0x30, 0x30, 0x3C, 0x30, 0x3C, 0x3C, 0x33, 0x3C, 0x33, 0xF3, 0x30, 0xF3, 0x30, 0x30, 0x30, 0x30, 0x30,
+    0x30, 0x30, 0x30, 0x30, 0x30, 0x3F, 0x30, 0x3F, 0x33, 0x33, 0x33, 0x33, 0xF0, 0x33, 0xF0, 0x33, 0x30, 0x30, 0x30, 0x30,
+    0x30, 0x30, 0x30, 0x30, 0x30, 0x3C, 0x30, 0x3C, 0x33, 0x33, 0x33, 0x33, 0xF0, 0x30, 0xF0, 0x30, 0x30, 0x30, 0x30, 0x30,
+    0x30, 0x30, 0x30, 0x30, 0x33, 0x3F, 0x33, 0x3F, 0x3C, 0x33, 0x3C, 0x33, 0xF3, 0x33, 0xF3, 0x33, 0x30, 0x30, 0x30, 0x30,
+    0x30, 0x30, 0x30, 0x30, 0x33, 0x3C, 0x33, 0x3C, 0x3C, 0x33, 0x3C, 0x33, 0xF3, 0x30, 0xF3, 0x30, 0x30, 0x30, 0x30, 0x30,
+    0x00, 0x00, 0x00, 0x00, 0xFC, 0x3F, 0xFC, 0x3F, 0x03, 0x00, 0x03, 0x00, 0x03, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0xFC, 0x3F, 0xFC, 0x3F, 0x0C, 0x30, 0x0C, 0x30, 0x0C, 0x30, 0x0C, 0x30, 0x0C, 0x30, 0x0C, 0x30, 0xFC, 0x3F, 0xFC, 0x3F
+];
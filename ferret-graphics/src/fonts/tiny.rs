@@ -0,0 +1,312 @@
+use crate::fonts::font::Font;
+use crate::fonts::font::GlyphRange;
+
+/// 3x5 font for very small screens. Lower case letters use the upper case
+/// glyphs and accented letters draw without their accents.
+pub const TINY_FONT: Font<'static> = Font {
+    data: &TINY_FONT_DATA,
+    cell_width: 3,
+    cell_height: 5,
+    widths: None,
+    spacing: 1,
+    baseline: 4,
+    line_height: 6,
+    glyphs: &[
+        GlyphRange { first: ' ', last: '_', glyph: 0 },
+        GlyphRange { first: 'a', last: 'z', glyph: 'A' as u16 - ' ' as u16 },
+        GlyphRange { first: '\u{a0}', last: '\u{17f}', glyph: 64 }
+    ],
+    fallback: Some(64 + 224)
+};
+
+pub const TINY_FONT_DATA: [u8; 867] = [
+    0x00, 0x00, 0x00,
+    0x00, 0x17, 0x00,
+    0x03, 0x00, 0x03,
+    0x1F, 0x0A, 0x1F,
+    0x12, 0x1F, 0x09,
+    0x09, 0x04, 0x12,
+    0x0F, 0x17, 0x1C,
+    0x00, 0x03, 0x00,
+    0x00, 0x0E, 0x11,
+    0x11, 0x0E, 0x00,
+    0x05, 0x02, 0x05,
+    0x04, 0x0E, 0x04,
+    0x10, 0x08, 0x00,
+    0x04, 0x04, 0x04,
+    0x00, 0x10, 0x00,
+    0x18, 0x04, 0x03,
+    0x1E, 0x11, 0x0F,
+    0x12, 0x1F, 0x10,
+    0x19, 0x15, 0x12,
+    0x11, 0x15, 0x0A,
+    0x07, 0x04, 0x1F,
+    0x17, 0x15, 0x09,
+    0x1E, 0x15, 0x1D,
+    0x19, 0x05, 0x03,
+    0x1F, 0x15, 0x1F,
+    0x17, 0x15, 0x0F,
+    0x00, 0x0A, 0x00,
+    0x10, 0x0A, 0x00,
+    0x04, 0x0A, 0x11,
+    0x0A, 0x0A, 0x0A,
+    0x11, 0x0A, 0x04,
+    0x01, 0x15, 0x03,
+    0x0E, 0x15, 0x16,
+    0x1E, 0x05, 0x1E,
+    0x1F, 0x15, 0x0A,
+    0x0E, 0x11, 0x11,
+    0x1F, 0x11, 0x0E,
+    0x1F, 0x15, 0x15,
+    0x1F, 0x05, 0x05,
+    0x0E, 0x11, 0x1D,
+    0x1F, 0x04, 0x1F,
+    0x11, 0x1F, 0x11,
+    0x08, 0x10, 0x0F,
+    0x1F, 0x04, 0x1B,
+    0x1F, 0x10, 0x10,
+    0x1F, 0x06, 0x1F,
+    0x1F, 0x0E, 0x1F,
+    0x0E, 0x11, 0x0E,
+    0x1F, 0x05, 0x02,
+    0x0E, 0x19, 0x1E,
+    0x1F, 0x0D, 0x16,
+    0x12, 0x15, 0x09,
+    0x01, 0x1F, 0x01,
+    0x0F, 0x10, 0x1F,
+    0x07, 0x18, 0x07,
+    0x1F, 0x0C, 0x1F,
+    0x1B, 0x04, 0x1B,
+    0x03, 0x1C, 0x03,
+    0x19, 0x15, 0x13,
+    0x1F, 0x11, 0x11,
+    0x03, 0x04, 0x18,
+    0x11, 0x11, 0x1F,
+    0x02, 0x01, 0x02,
+    0x10, 0x10, 0x10,
+    0x00, 0x00, 0x00,
+    0x00, 0x17, 0x00,
+    0x1F, 0x11, 0x1F,
+    0x1F, 0x11, 0x1F,
+    0x1F, 0x11, 0x1F,
+    0x1F, 0x11, 0x1F,
+    0x1F, 0x11, 0x1F,
+    0x1F, 0x11, 0x1F,
+    0x1F, 0x11, 0x1F,
+    0x1F, 0x11, 0x1F,
+    0x1E, 0x05, 0x1E,
+    0x1F, 0x11, 0x1F,
+    0x1F, 0x11, 0x1F,
+    0x04, 0x04, 0x04,
+    0x1F, 0x11, 0x1F,
+    0x1F, 0x11, 0x1F,
+    0x1F, 0x11, 0x1F,
+    0x1F, 0x11, 0x1F,
+    0x19, 0x15, 0x12,
+    0x11, 0x15, 0x0A,
+    0x1F, 0x11, 0x1F,
+    0x1F, 0x11, 0x1F,
+    0x1F, 0x11, 0x1F,
+    0x00, 0x10, 0x00,
+    0x1F, 0x11, 0x1F,
+    0x12, 0x1F, 0x10,
+    0x0E, 0x11, 0x0E,
+    0x1F, 0x11, 0x1F,
+    0x1F, 0x11, 0x1F,
+    0x1F, 0x11, 0x1F,
+    0x1F, 0x11, 0x1F,
+    0x01, 0x15, 0x03,
+    0x1E, 0x05, 0x1E,
+    0x1E, 0x05, 0x1E,
+    0x1E, 0x05, 0x1E,
+    0x1E, 0x05, 0x1E,
+    0x1E, 0x05, 0x1E,
+    0x1E, 0x05, 0x1E,
+    0x1E, 0x05, 0x1E,
+    0x0E, 0x11, 0x11,
+    0x1F, 0x15, 0x15,
+    0x1F, 0x15, 0x15,
+    0x1F, 0x15, 0x15,
+    0x1F, 0x15, 0x15,
+    0x11, 0x1F, 0x11,
+    0x11, 0x1F, 0x11,
+    0x11, 0x1F, 0x11,
+    0x11, 0x1F, 0x11,
+    0x1F, 0x11, 0x0E,
+    0x1F, 0x0E, 0x1F,
+    0x0E, 0x11, 0x0E,
+    0x0E, 0x11, 0x0E,
+    0x0E, 0x11, 0x0E,
+    0x0E, 0x11, 0x0E,
+    0x0E, 0x11, 0x0E,
+    0x1B, 0x04, 0x1B,
+    0x0E, 0x11, 0x0E,
+    0x0F, 0x10, 0x1F,
+    0x0F, 0x10, 0x1F,
+    0x0F, 0x10, 0x1F,
+    0x0F, 0x10, 0x1F,
+    0x03, 0x1C, 0x03,
+    0x1F, 0x11, 0x1F,
+    0x1F, 0x15, 0x0A,
+    0x1E, 0x05, 0x1E,
+    0x1E, 0x05, 0x1E,
+    0x1E, 0x05, 0x1E,
+    0x1E, 0x05, 0x1E,
+    0x1E, 0x05, 0x1E,
+    0x1E, 0x05, 0x1E,
+    0x1E, 0x05, 0x1E,
+    0x0E, 0x11, 0x11,
+    0x1F, 0x15, 0x15,
+    0x1F, 0x15, 0x15,
+    0x1F, 0x15, 0x15,
+    0x1F, 0x15, 0x15,
+    0x11, 0x1F, 0x11,
+    0x11, 0x1F, 0x11,
+    0x11, 0x1F, 0x11,
+    0x11, 0x1F, 0x11,
+    0x1F, 0x11, 0x0E,
+    0x1F, 0x0E, 0x1F,
+    0x0E, 0x11, 0x0E,
+    0x0E, 0x11, 0x0E,
+    0x0E, 0x11, 0x0E,
+    0x0E, 0x11, 0x0E,
+    0x0E, 0x11, 0x0E,
+    0x1F, 0x11, 0x1F,
+    0x0E, 0x11, 0x0E,
+    0x0F, 0x10, 0x1F,
+    0x0F, 0x10, 0x1F,
+    0x0F, 0x10, 0x1F,
+    0x0F, 0x10, 0x1F,
+    0x03, 0x1C, 0x03,
+    0x1F, 0x11, 0x1F,
+    0x03, 0x1C, 0x03,
+    0x1E, 0x05, 0x1E,
+    0x1E, 0x05, 0x1E,
+    0x1E, 0x05, 0x1E,
+    0x1E, 0x05, 0x1E,
+    0x1E, 0x05, 0x1E,
+    0x1E, 0x05, 0x1E,
+    0x0E, 0x11, 0x11,
+    0x0E, 0x11, 0x11,
+    0x0E, 0x11, 0x11,
+    0x0E, 0x11, 0x11,
+    0x0E, 0x11, 0x11,
+    0x0E, 0x11, 0x11,
+    0x0E, 0x11, 0x11,
+    0x0E, 0x11, 0x11,
+    0x1F, 0x11, 0x0E,
+    0x1F, 0x11, 0x0E,
+    0x1F, 0x11, 0x0E,
+    0x1F, 0x11, 0x0E,
+    0x1F, 0x15, 0x15,
+    0x1F, 0x15, 0x15,
+    0x1F, 0x15, 0x15,
+    0x1F, 0x15, 0x15,
+    0x1F, 0x15, 0x15,
+    0x1F, 0x15, 0x15,
+    0x1F, 0x15, 0x15,
+    0x1F, 0x15, 0x15,
+    0x1F, 0x15, 0x15,
+    0x1F, 0x15, 0x15,
+    0x0E, 0x11, 0x1D,
+    0x0E, 0x11, 0x1D,
+    0x0E, 0x11, 0x1D,
+    0x0E, 0x11, 0x1D,
+    0x0E, 0x11, 0x1D,
+    0x0E, 0x11, 0x1D,
+    0x0E, 0x11, 0x1D,
+    0x0E, 0x11, 0x1D,
+    0x1F, 0x04, 0x1F,
+    0x1F, 0x04, 0x1F,
+    0x1F, 0x04, 0x1F,
+    0x1F, 0x04, 0x1F,
+    0x11, 0x1F, 0x11,
+    0x11, 0x1F, 0x11,
+    0x11, 0x1F, 0x11,
+    0x11, 0x1F, 0x11,
+    0x11, 0x1F, 0x11,
+    0x11, 0x1F, 0x11,
+    0x11, 0x1F, 0x11,
+    0x11, 0x1F, 0x11,
+    0x11, 0x1F, 0x11,
+    0x11, 0x1F, 0x11,
+    0x1F, 0x11, 0x1F,
+    0x1F, 0x11, 0x1F,
+    0x08, 0x10, 0x0F,
+    0x08, 0x10, 0x0F,
+    0x1F, 0x04, 0x1B,
+    0x1F, 0x04, 0x1B,
+    0x1F, 0x04, 0x1B,
+    0x1F, 0x10, 0x10,
+    0x1F, 0x10, 0x10,
+    0x1F, 0x10, 0x10,
+    0x1F, 0x10, 0x10,
+    0x1F, 0x10, 0x10,
+    0x1F, 0x10, 0x10,
+    0x1F, 0x11, 0x1F,
+    0x1F, 0x11, 0x1F,
+    0x1F, 0x10, 0x10,
+    0x1F, 0x10, 0x10,
+    0x1F, 0x0E, 0x1F,
+    0x1F, 0x0E, 0x1F,
+    0x1F, 0x0E, 0x1F,
+    0x1F, 0x0E, 0x1F,
+    0x1F, 0x0E, 0x1F,
+    0x1F, 0x0E, 0x1F,
+    0x1F, 0x11, 0x1F,
+    0x1F, 0x0E, 0x1F,
+    0x1F, 0x0E, 0x1F,
+    0x0E, 0x11, 0x0E,
+    0x0E, 0x11, 0x0E,
+    0x0E, 0x11, 0x0E,
+    0x0E, 0x11, 0x0E,
+    0x0E, 0x11, 0x0E,
+    0x0E, 0x11, 0x0E,
+    0x0E, 0x11, 0x0E,
+    0x0E, 0x11, 0x0E,
+    0x1F, 0x0D, 0x16,
+    0x1F, 0x0D, 0x16,
+    0x1F, 0x0D, 0x16,
+    0x1F, 0x0D, 0x16,
+    0x1F, 0x0D, 0x16,
+    0x1F, 0x0D, 0x16,
+    0x12, 0x15, 0x09,
+    0x12, 0x15, 0x09,
+    0x12, 0x15, 0x09,
+    0x12, 0x15, 0x09,
+    0x12, 0x15, 0x09,
+    0x12, 0x15, 0x09,
+    0x12, 0x15, 0x09,
+    0x12, 0x15, 0x09,
+    0x01, 0x1F, 0x01,
+    0x01, 0x1F, 0x01,
+    0x01, 0x1F, 0x01,
+    0x01, 0x1F, 0x01,
+    0x01, 0x1F, 0x01,
+    0x01, 0x1F, 0x01,
+    0x0F, 0x10, 0x1F,
+    0x0F, 0x10, 0x1F,
+    0x0F, 0x10, 0x1F,
+    0x0F, 0x10, 0x1F,
+    0x0F, 0x10, 0x1F,
+    0x0F, 0x10, 0x1F,
+    0x0F, 0x10, 0x1F,
+    0x0F, 0x10, 0x1F,
+    0x0F, 0x10, 0x1F,
+    0x0F, 0x10, 0x1F,
+    0x0F, 0x10, 0x1F,
+    0x0F, 0x10, 0x1F,
+    0x1F, 0x0C, 0x1F,
+    0x1F, 0x0C, 0x1F,
+    0x03, 0x1C, 0x03,
+    0x03, 0x1C, 0x03,
+    0x03, 0x1C, 0x03,
+    0x19, 0x15, 0x13,
+    0x19, 0x15, 0x13,
+    0x19, 0x15, 0x13,
+    0x19, 0x15, 0x13,
+    0x19, 0x15, 0x13,
+    0x19, 0x15, 0x13,
+    0x12, 0x15, 0x09,
+    0x1F, 0x11, 0x1F
+];
@@ -0,0 +1,14 @@
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum InterlaceField {
+    Even,
+    Odd
+}
+
+impl InterlaceField {
+    pub fn contains_row(&self, y: u16) -> bool {
+        match self {
+            InterlaceField::Even => y & 1 == 0,
+            InterlaceField::Odd => y & 1 == 1
+        }
+    }
+}
@@ -1,35 +1,104 @@
-#![no_std]
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "simd", feature(portable_simd))]
 
+#[cfg(test)]
+extern crate self as ferret_graphics;
+
+pub mod animation;
 pub mod color;
+mod draw_2d;
 pub mod fonts;
 pub mod ferret_3d;
+pub mod interlace_field;
+pub mod perspective_floor;
+#[cfg(feature = "std")]
+mod parallel;
+pub mod post_process;
 pub mod rect;
 pub mod screen_trait;
+pub mod sprite_options;
+pub mod sprite_sheet;
+pub mod sprite_transform;
+pub mod text_layout;
+pub mod tilemap;
 
 use crate::screen_trait::ScreenTrait;
 use crate::ferret_3d::pixel_data::PixelData;
+use crate::ferret_3d::pixel_row::PixelRow;
+use crate::ferret_3d::pixel_row::LANES;
+use crate::ferret_3d::pixel_row::coverage_mask;
+use crate::ferret_3d::pixel_row::depth_lanes;
+use crate::ferret_3d::pixel_row::depth_mask;
+use crate::ferret_3d::pixel_row::lane_mask;
+use crate::ferret_3d::scan_edge::ScanEdge;
 use crate::ferret_3d::triangle_edge_test::TriangleEdgeTest;
 use crate::ferret_3d::edge_data::EdgeData;
 use crate::ferret_3d::triangle_equations::TriangleEquations;
+use crate::ferret_3d::triangle_shading::TriangleShading;
 use crate::ferret_3d::vertex_shader_trait::VertexShaderTrait;
+use crate::ferret_3d::instanced_vertex_shader_trait::InstancedVertexShaderTrait;
+use crate::ferret_3d::material::Material;
+use crate::ferret_3d::material::MaterialPixelShader;
+use crate::ferret_3d::model::Model;
+use crate::ferret_3d::instance_data::InstanceData;
+use crate::ferret_3d::typed_shader::TypedPixelShader;
+use crate::ferret_3d::typed_shader::TypedPixelShaderTrait;
+use crate::ferret_3d::typed_shader::TypedVertexShaderTrait;
+use crate::ferret_3d::varyings::Varyings;
 use crate::ferret_3d::pixel_shader_trait::PixelShaderTrait;
+use crate::ferret_3d::ordering_table::OrderingTable;
+use crate::ferret_3d::draw_mesh_error::DrawMeshError;
+use crate::ferret_3d::blend_mode::BlendMode;
+use crate::ferret_3d::cull_mode::CullMode;
+use crate::ferret_3d::pixel_context::PixelContext;
+use crate::ferret_3d::rasterizer::Rasterizer;
+use crate::ferret_3d::render_scale::RenderScale;
+use crate::ferret_3d::transparent_queue::TransparentQueue;
+use crate::post_process::depth_buffer_view::DepthBufferView;
+use crate::post_process::post_process_trait::PostProcessTrait;
 use crate::color::Color;
+use crate::draw_2d::ceil_i32;
+use crate::fonts::font::Font;
+use crate::text_layout::TextAlign;
+use crate::text_layout::TextStyle;
+use crate::text_layout::TextWrap;
 
 use nalgebra::SVector;
 
 pub use rect::Rect;
+pub use ferret_derive::Varyings;
 
 const BLOCK_SIZE: usize = 8;
 const DEPTH_RANGE_FAR: f32 = 1.0;
 const DEPTH_RANGE_NEAR: f32 = 0.0;
 
+// Each block row is drawn as one packed row
+const _: () = assert!(BLOCK_SIZE == LANES);
+
 pub struct FerretGraphics<'a, TScreen>
 where
     TScreen: ScreenTrait
 {
     screen: TScreen,
-    depth_buffer: &'a mut [f32],
-    depth_test: bool
+    depth_buffer: Option<&'a mut [f32]>,
+    coarse_depth_buffer: Option<&'a mut [f32]>,
+    depth_test: bool,
+    depth_write: bool,
+    depth_bias_constant: f32,
+    depth_bias_slope_scale: f32,
+    blend_mode: BlendMode,
+    cull_mode: CullMode,
+    rasterizer: Rasterizer,
+    interlaced_render: bool,
+    scene_buffer: Option<&'a mut [u8]>,
+    render_scale: RenderScale,
+    id_buffer: Option<&'a mut [u16]>,
+    object_id: u16,
+
+    // First and past-the-end row drawn. The depth, coarse depth and ID
+    // buffers start at the first row, so a band of the screen can be
+    // rendered with its own slices.
+    rows: (usize, usize)
 }
 
 impl<'a, TScreen> FerretGraphics<'a, TScreen>
@@ -39,8 +108,44 @@ where
     pub fn new(screen: TScreen, depth_buffer: &'a mut [f32]) -> Self {
         Self {
             screen,
-            depth_buffer,
-            depth_test: false
+            depth_buffer: Some(depth_buffer),
+            coarse_depth_buffer: None,
+            depth_test: false,
+            depth_write: true,
+            depth_bias_constant: 0.0,
+            depth_bias_slope_scale: 0.0,
+            blend_mode: BlendMode::Opaque,
+            cull_mode: CullMode::Back,
+            rasterizer: Rasterizer::HalfSpace,
+            interlaced_render: false,
+            scene_buffer: None,
+            render_scale: RenderScale::Full,
+            id_buffer: None,
+            object_id: 0,
+            rows: (0, usize::MAX)
+        }
+    }
+
+    /// Creates a renderer with no depth buffer, meant to be used with
+    /// `draw_mesh_ordered` where draw order replaces depth testing.
+    pub fn new_without_depth_buffer(screen: TScreen) -> Self {
+        Self {
+            screen,
+            depth_buffer: None,
+            coarse_depth_buffer: None,
+            depth_test: false,
+            depth_write: true,
+            depth_bias_constant: 0.0,
+            depth_bias_slope_scale: 0.0,
+            blend_mode: BlendMode::Opaque,
+            cull_mode: CullMode::Back,
+            rasterizer: Rasterizer::HalfSpace,
+            interlaced_render: false,
+            scene_buffer: None,
+            render_scale: RenderScale::Full,
+            id_buffer: None,
+            object_id: 0,
+            rows: (0, usize::MAX)
         }
     }
 
@@ -48,72 +153,259 @@ where
         self.depth_test = value;
     }
 
+    pub fn set_depth_write(&mut self, value: bool) {
+        self.depth_write = value;
+    }
+
+    /// Number of values needed by `set_coarse_depth_buffer` for a screen of
+    /// the given size: a min/max pair per 8x8 block.
+    pub const fn coarse_depth_buffer_size(width: usize, height: usize) -> usize {
+        let blocks_x = width.div_ceil(BLOCK_SIZE);
+        let blocks_y = height.div_ceil(BLOCK_SIZE);
+
+        blocks_x * blocks_y * 2
+    }
+
+    /// Sets the buffer holding the min/max depth of every 8x8 block. When
+    /// present, blocks a triangle can't pass the depth test in are skipped
+    /// whole, and blocks it passes everywhere skip the per-pixel depth reads.
+    /// It is reset by `clear_depth_buffer`.
+    pub fn set_coarse_depth_buffer(&mut self, coarse_depth_buffer: Option<&'a mut [f32]>) {
+        self.coarse_depth_buffer = coarse_depth_buffer;
+    }
+
+    /// Sets the buffer receiving the object ID of every shaded pixel, one
+    /// value per pixel of the 3D scene. It is reset by `clear_depth_buffer`.
+    pub fn set_id_buffer(&mut self, id_buffer: Option<&'a mut [u16]>) {
+        self.id_buffer = id_buffer;
+    }
+
+    /// ID written to the ID buffer by the following draw calls. 0 is
+    /// reserved for "no object".
+    pub fn set_object_id(&mut self, object_id: u16) {
+        self.object_id = object_id;
+    }
+
+    /// Object ID of the nearest geometry drawn at screen position `x`, `y`.
+    pub fn pick(&self, x: u16, y: u16) -> Option<u16> {
+        let scale = self.scale_factor();
+        let (x, y) = (x / scale, y / scale);
+
+        if x >= self.target_width() || y >= self.target_height() {
+            return None;
+        }
+
+        let index = y as usize * self.target_width() as usize + x as usize;
+
+        match self.id_buffer.as_ref().map(|id_buffer| id_buffer[index]) {
+            Some(0) | None => None,
+            id => id
+        }
+    }
+
+    pub fn set_rasterizer(&mut self, rasterizer: Rasterizer) {
+        self.rasterizer = rasterizer;
+    }
+
+    /// When enabled, 3D draws only shade the rows of the field the screen
+    /// will send next (see `ScreenTrait::get_interlace_field`), the other
+    /// rows keep the previous frame.
+    pub fn set_interlaced_render(&mut self, value: bool) {
+        self.interlaced_render = value;
+    }
+
+    /// Renders the 3D scene into `scene_buffer` (RGB888, see
+    /// `RenderScale::scene_buffer_size`) at a fraction of the screen size.
+    /// Depth buffers are indexed at that size too. Call `resolve_scene` to
+    /// upscale it onto the screen before drawing UI and text.
+    pub fn set_render_scale(&mut self, render_scale: RenderScale, scene_buffer: Option<&'a mut [u8]>) {
+        self.render_scale = render_scale;
+        self.scene_buffer = scene_buffer;
+    }
+
+    /// Copies the scene buffer onto the screen with nearest-neighbour
+    /// upscaling. Does nothing when rendering at full scale. When the screen
+    /// size isn't a multiple of the scale, the last scene row and column
+    /// are stretched over the leftover screen edge.
+    pub fn resolve_scene(&mut self) {
+        let scale = self.scale_factor();
+
+        if scale == 1 {
+            return;
+        }
+
+        let target_width = self.target_width();
+        let target_height = self.target_height();
+
+        if target_width == 0 || target_height == 0 {
+            return;
+        }
+
+        if let Some(scene_buffer) = &self.scene_buffer {
+            for y in 0..self.screen.get_height() {
+                let source_y = (y / scale).min(target_height - 1) as usize;
+
+                for x in 0..self.screen.get_width() {
+                    let source_x = (x / scale).min(target_width - 1) as usize;
+                    let index = (source_y * target_width as usize + source_x) * 3;
+
+                    self.screen.set_pixel(x, y, scene_buffer[index], scene_buffer[index + 1], scene_buffer[index + 2]);
+                }
+            }
+        }
+    }
+
+    /// Blending of the following 3D draws with what is already drawn. The
+    /// depth buffer is still written unless depth writes are turned off.
+    pub fn set_blend_mode(&mut self, blend_mode: BlendMode) {
+        self.blend_mode = blend_mode;
+    }
+
+    /// Side of the following 3D draws left out, back faces by default.
+    pub fn set_cull_mode(&mut self, cull_mode: CullMode) {
+        self.cull_mode = cull_mode;
+    }
+
+    /// Offsets the depth of the following draws towards the camera by
+    /// `constant` plus `slope_scale` times the triangle's depth slope, so
+    /// decals and overlays win the depth test against coplanar surfaces.
+    /// The bias stays until changed, set it back to zero after the decals.
+    pub fn set_depth_bias(&mut self, constant: f32, slope_scale: f32) {
+        self.depth_bias_constant = constant;
+        self.depth_bias_slope_scale = slope_scale;
+    }
+
     pub fn clear_color_buffer(&mut self, color: Color) {
         let rgb = color.as_rgb888();
         self.screen.clear(rgb.0, rgb.1, rgb.2);
+
+        if let Some(scene_buffer) = &mut self.scene_buffer {
+            for pixel in scene_buffer.chunks_exact_mut(3) {
+                pixel[0] = rgb.0;
+                pixel[1] = rgb.1;
+                pixel[2] = rgb.2;
+            }
+        }
     }
 
     pub fn clear_depth_buffer(&mut self, depth: f32) {
-        for x in 0..self.depth_buffer.len() {
-            self.depth_buffer[x] = depth;
+        if let Some(depth_buffer) = &mut self.depth_buffer {
+            for x in 0..depth_buffer.len() {
+                depth_buffer[x] = depth;
+            }
+        }
+
+        if let Some(coarse_depth_buffer) = &mut self.coarse_depth_buffer {
+            for x in 0..coarse_depth_buffer.len() {
+                coarse_depth_buffer[x] = depth;
+            }
+        }
+
+        if let Some(id_buffer) = &mut self.id_buffer {
+            id_buffer.fill(0);
         }
     }
 
     pub fn draw_string<'b>
     (
         &mut self,
-        mut x: u16,
-        mut y: u16,
+        x: u16,
+        y: u16,
         text: &'b str,
-        font: &[u8],
+        font: &Font,
         font_color: Color,
         bg_color: Option<Color>
     )
     {
-        let original_x = x;
-        for ch in text.chars() {
-
-            if ch == '\n' {
-                x = original_x;
-                y += 9;
-                continue;
-            }
-
-            self.draw_char(x, y, ch, font, font_color, bg_color);
-            x += 6;
-
-            if x >= self.screen.get_width() {
-                break;
-            }
-        }
+        self.draw_chars(x, y, text.chars(), font, font_color, bg_color);
     }
 
     pub fn draw_char_array(
         &mut self,
-        mut x: u16,
-        mut y: u16,
+        x: u16,
+        y: u16,
         ch_array: &[char],
         start: usize,
-        font: &[u8],
+        font: &Font,
         font_color: Color,
         bg_color: Option<Color>
     ) {
-        // TODO: can we reduce duplication string -> char_array ?
+        self.draw_chars(x, y, ch_array.iter().skip(start).copied(), font, font_color, bg_color);
+    }
+
+    /// Lines run until the next '\n', characters past the right edge of the
+    /// screen are skipped.
+    fn draw_chars<I: Iterator<Item = char>>(&mut self, mut x: u16, mut y: u16, chars: I, font: &Font, font_color: Color, bg_color: Option<Color>) {
         let original_x = x;
-        for ch_index in start..ch_array.len() {
-            let ch = ch_array[ch_index];
+
+        for ch in chars {
+            if y >= self.screen.get_height() {
+                break;
+            }
+
             if ch == '\n' {
                 x = original_x;
-                y += 9;
+                y = y.saturating_add(font.line_height as u16);
+                continue;
+            }
+
+            if x >= self.screen.get_width() {
                 continue;
             }
 
             self.draw_char(x, y, ch, font, font_color, bg_color);
-            x += 6;
+            x = x.saturating_add(font.advance(ch));
+        }
+    }
 
-            if x >= self.screen.get_width() {
+    /// Draws `text` inside `rect`, aligning every line and breaking or
+    /// truncating lines wider than it according to `style`. Nothing is drawn
+    /// outside of `rect`.
+    pub fn draw_text_in(&mut self, rect: Rect<i32>, text: &str, style: &TextStyle) {
+        let font = style.font;
+        let wrap = style.wrap;
+        let max_width = (rect.right - rect.left).max(0) as u16;
+        let mut rest = Some(text);
+        let mut y = rect.top;
+
+        while let Some(text) = rest {
+            if y >= rect.bottom {
                 break;
             }
+
+            let (line, next) = text_layout::next_line(text, font, max_width, wrap);
+
+            let truncated = if wrap == TextWrap::Ellipsis && text_layout::line_width(line, font) > max_width {
+                Some(text_layout::truncate_with_ellipsis(line, font, max_width))
+            } else {
+                None
+            };
+
+            let width = match truncated {
+                Some(truncated) => text_layout::line_width(truncated, font) + font.advance('.') * 3,
+                None => text_layout::line_width(line, font)
+            } as i32;
+
+            let mut x = match style.align {
+                TextAlign::Left => rect.left,
+                TextAlign::Center => rect.left + (max_width as i32 - width) / 2,
+                TextAlign::Right => rect.right - width
+            };
+
+            for c in truncated.unwrap_or(line).chars() {
+                self.draw_char_clipped(x, y, c, style, &rect);
+                x += font.advance(c) as i32;
+            }
+
+            if truncated.is_some() {
+                for _ in 0..3 {
+                    self.draw_char_clipped(x, y, '.', style, &rect);
+                    x += font.advance('.') as i32;
+                }
+            }
+
+            rest = next;
+            y += font.line_height as i32;
         }
     }
 
@@ -122,103 +414,370 @@ where
         x: u16,
         y: u16,
         c: char,
-        font: &[u8],
+        font: &Font,
         font_color: Color,
         bg_color: Option<Color>
     ) {
-        let font_color = font_color.as_rgb888();
-        let mut mask: u8 = 0x01;
+        let screen = Rect::new(0, 0, self.screen.get_width() as i32, self.screen.get_height() as i32);
+        let style = TextStyle {
+            bg_color,
+            ..TextStyle::new(font, font_color)
+        };
 
-        if let Some(bg_color) = bg_color {
-            let bg_color = bg_color.as_rgb888();
-            // solid bg
-            let mut color: (u8, u8, u8);
+        self.draw_char_clipped(x as i32, y as i32, c, &style, &screen);
+    }
 
-            for yc in 0..8 {
+    fn draw_char_clipped(&mut self, x: i32, y: i32, c: char, style: &TextStyle, clip: &Rect<i32>) {
+        let font = style.font;
+        let glyph = match font.glyph(c) {
+            Some(glyph) => glyph,
+            None => return
+        };
 
-                // If out of bounds, stop
-                if yc + y >= self.screen.get_height() {
-                    break;
+        let font_color = style.font_color.as_rgb888();
+        let bg_color = style.bg_color.map(|bg_color| bg_color.as_rgb888());
+
+        let min_x = clip.left.max(0);
+        let min_y = clip.top.max(0);
+        let max_x = clip.right.min(self.screen.get_width() as i32);
+        let max_y = clip.bottom.min(self.screen.get_height() as i32);
+
+        for xc in 0..font.glyph_width(glyph) as i32 {
+            if x + xc < min_x || x + xc >= max_x {
+                continue;
+            }
+
+            let column = font.column_data(glyph, xc as usize);
+
+            for yc in 0..font.cell_height as i32 {
+                if y + yc < min_y || y + yc >= max_y {
+                    continue;
                 }
 
-                for xc in 0..5 {
+                let color = if column[yc as usize / 8] & 1 << (yc % 8) != 0 {
+                    font_color
+                } else if let Some(bg_color) = bg_color {
+                    bg_color
+                } else {
+                    continue;
+                };
 
-                    // If out of bounds, stop
-                    if xc + x >=  self.screen.get_width() {
-                        break;
-                    }
+                self.screen.set_pixel((x + xc) as u16, (y + yc) as u16, color.0, color.1, color.2);
+            }
+        }
+    }
+
+    pub fn draw_mesh
+        <TVertexShader: VertexShaderTrait<TVECTOR_IN_SIZE, TVECTOR_OUT_SIZE> + ?Sized,
+        TPixelShader: PixelShaderTrait<TVECTOR_OUT_SIZE> + ?Sized,
+        const TVECTOR_IN_SIZE: usize,
+        const TVECTOR_OUT_SIZE: usize>(
+            &mut self,
+            vertices: &[SVector<f32, TVECTOR_IN_SIZE>],
+            indexes: &[(usize, usize, usize)],
+            vertex_shader: &TVertexShader,
+            pixel_shader: &TPixelShader
+        ) {
+            for i in indexes {
+                // TODO: reduce duplicated vertex processing
+                let v0 = vertex_shader.process(&vertices[i.0]);
+                let v1 = vertex_shader.process(&vertices[i.1]);
+                let v2 = vertex_shader.process(&vertices[i.2]);
+
+                debug_assert!(Self::is_vertex_finite(&v0), "vertex {} is not finite", i.0);
+                debug_assert!(Self::is_vertex_finite(&v1), "vertex {} is not finite", i.1);
+                debug_assert!(Self::is_vertex_finite(&v2), "vertex {} is not finite", i.2);
+
+                self.draw_clip_triangle(pixel_shader, v0, v1, v2);
+            }
+    }
+
+    /// Draws the same mesh once per entry of `instances`. The vertex data is
+    /// shared and each vertex is processed together with its instance.
+    pub fn draw_mesh_instanced
+        <TVertexShader: InstancedVertexShaderTrait<TVECTOR_IN_SIZE, TVECTOR_OUT_SIZE> + ?Sized,
+        TPixelShader: PixelShaderTrait<TVECTOR_OUT_SIZE> + ?Sized,
+        const TVECTOR_IN_SIZE: usize,
+        const TVECTOR_OUT_SIZE: usize>(
+            &mut self,
+            vertices: &[SVector<f32, TVECTOR_IN_SIZE>],
+            indexes: &[(usize, usize, usize)],
+            instances: &[InstanceData],
+            vertex_shader: &TVertexShader,
+            pixel_shader: &TPixelShader
+        ) {
+            for instance in instances {
+                for i in indexes {
+                    let v0 = vertex_shader.process(instance, &vertices[i.0]);
+                    let v1 = vertex_shader.process(instance, &vertices[i.1]);
+                    let v2 = vertex_shader.process(instance, &vertices[i.2]);
+
+                    self.draw_clip_triangle(pixel_shader, v0, v1, v2);
+                }
+            }
+    }
+
+    /// Same as `draw_mesh`, with vertices and varyings described by structs
+    /// deriving `Varyings`. The pixel shader input must be the vertex shader
+    /// output type, so mismatched slots fail to compile.
+    pub fn draw_mesh_typed
+        <TVertexShader: TypedVertexShaderTrait + ?Sized,
+        TPixelShader: TypedPixelShaderTrait<Input = TVertexShader::Output> + ?Sized,
+        const TVECTOR_OUT_SIZE: usize>(
+            &mut self,
+            vertices: &[TVertexShader::Input],
+            indexes: &[(usize, usize, usize)],
+            vertex_shader: &TVertexShader,
+            pixel_shader: &TPixelShader
+        )
+    where
+        TVertexShader::Output: Varyings<TVECTOR_OUT_SIZE>
+    {
+        let pixel_shader = TypedPixelShader(pixel_shader);
+
+        for i in indexes {
+            let v0 = vertex_shader.process(&vertices[i.0]).to_vector();
+            let v1 = vertex_shader.process(&vertices[i.1]).to_vector();
+            let v2 = vertex_shader.process(&vertices[i.2]).to_vector();
+
+            self.draw_clip_triangle(&pixel_shader, v0, v1, v2);
+        }
+    }
 
-                    if font[c as usize * 5 + xc as usize] & mask != 0 {
-                        color = font_color;
-                    } else {
-                        color = bg_color;
+    /// Checked version of `draw_mesh`. Indexes and input vertices are
+    /// validated before anything is drawn; triangles whose vertex shader
+    /// output is not finite are skipped and the first one is reported.
+    pub fn try_draw_mesh
+        <TVertexShader: VertexShaderTrait<TVECTOR_IN_SIZE, TVECTOR_OUT_SIZE> + ?Sized,
+        TPixelShader: PixelShaderTrait<TVECTOR_OUT_SIZE> + ?Sized,
+        const TVECTOR_IN_SIZE: usize,
+        const TVECTOR_OUT_SIZE: usize>(
+            &mut self,
+            vertices: &[SVector<f32, TVECTOR_IN_SIZE>],
+            indexes: &[(usize, usize, usize)],
+            vertex_shader: &TVertexShader,
+            pixel_shader: &TPixelShader
+        ) -> Result<(), DrawMeshError> {
+            for (triangle, i) in indexes.iter().enumerate() {
+                for index in [i.0, i.1, i.2] {
+                    if index >= vertices.len() {
+                        return Err(DrawMeshError::IndexOutOfRange { triangle, index });
                     }
+                }
+            }
 
-                    self.screen.set_pixel(
-                        x + xc as u16,
-                        y + yc as u16,
-                        color.0,
-                        color.1,
-                        color.2
-                    );
+            for (index, vertex) in vertices.iter().enumerate() {
+                if !vertex.iter().all(|value| value.is_finite()) {
+                    return Err(DrawMeshError::NonFiniteVertex { index });
                 }
-                mask = mask << 1;
             }
-        } else {
-            // transparent bg
 
-            for yc in 0..8 {
-                for xc in 0..5 {
-                    if font[c as usize * 5 + xc] & mask == 0 {
-                        continue
+            let mut result = Ok(());
+
+            for i in indexes {
+                let v0 = vertex_shader.process(&vertices[i.0]);
+                let v1 = vertex_shader.process(&vertices[i.1]);
+                let v2 = vertex_shader.process(&vertices[i.2]);
+
+                let invalid_index =
+                    if !Self::is_vertex_finite(&v0) { Some(i.0) }
+                    else if !Self::is_vertex_finite(&v1) { Some(i.1) }
+                    else if !Self::is_vertex_finite(&v2) { Some(i.2) }
+                    else { None };
+
+                if let Some(index) = invalid_index {
+                    if result.is_ok() {
+                        result = Err(DrawMeshError::NonFiniteVertex { index });
                     }
 
-                    self.screen.set_pixel(
-                        x + xc as u16,
-                        y + yc as u16,
-                        font_color.0,
-                        font_color.1,
-                        font_color.2
-                    );
+                    continue;
+                }
+
+                self.draw_clip_triangle(pixel_shader, v0, v1, v2);
+            }
+
+            result
+    }
+
+    /// Draws each sub-mesh of `model` with its entry in `materials`, applying
+    /// the material's cull, depth and blend state for the duration of the
+    /// sub-mesh. Blended sub-meshes are drawn after the opaque ones without
+    /// depth writes; sorting them against other meshes is left to
+    /// `draw_mesh_transparent`.
+    pub fn draw_model<const TVECTOR_IN_SIZE: usize, const TVECTOR_OUT_SIZE: usize>(
+        &mut self,
+        model: &Model<'_, TVECTOR_IN_SIZE>,
+        materials: &[Material<'_, TVECTOR_IN_SIZE, TVECTOR_OUT_SIZE>]
+    ) {
+        let cull_mode = self.cull_mode;
+        let depth_test = self.depth_test;
+        let depth_write = self.depth_write;
+        let blend_mode = self.blend_mode;
+
+        // Opaque sub-meshes first, so blended ones land on top of them
+        for blended in [false, true] {
+            for sub_mesh in model.sub_meshes {
+                let material = &materials[sub_mesh.material];
+
+                if (material.blend_mode != BlendMode::Opaque) != blended {
+                    continue;
                 }
-                mask = mask << 1;
+
+                let pixel_shader = MaterialPixelShader {
+                    pixel_shader: material.pixel_shader,
+                    properties: &material.properties
+                };
+
+                self.cull_mode = material.cull_mode;
+                self.depth_test = material.depth_test;
+                self.depth_write = material.depth_write && !blended;
+                self.blend_mode = material.blend_mode;
+
+                self.draw_mesh(
+                    model.vertices,
+                    &model.indexes[sub_mesh.start..sub_mesh.start + sub_mesh.count],
+                    material.vertex_shader,
+                    &pixel_shader);
             }
         }
+
+        self.cull_mode = cull_mode;
+        self.depth_test = depth_test;
+        self.depth_write = depth_write;
+        self.blend_mode = blend_mode;
     }
 
-    pub fn draw_mesh
-        <TVertexShader: VertexShaderTrait<TVECTOR_IN_SIZE, TVECTOR_OUT_SIZE>,
+    /// Same as `draw_mesh`, but triangles are queued in `ordering_table`
+    /// instead of being rasterized. Call `flush_ordered` to draw them.
+    pub fn draw_mesh_ordered
+        <'s,
+        TVertexShader: VertexShaderTrait<TVECTOR_IN_SIZE, TVECTOR_OUT_SIZE> + ?Sized,
         TPixelShader: PixelShaderTrait<TVECTOR_OUT_SIZE>,
         const TVECTOR_IN_SIZE: usize,
         const TVECTOR_OUT_SIZE: usize>(
             &mut self,
+            ordering_table: &mut OrderingTable<'_, 's, TVECTOR_OUT_SIZE>,
             vertices: &[SVector<f32, TVECTOR_IN_SIZE>],
             indexes: &[(usize, usize, usize)],
             vertex_shader: &TVertexShader,
-            pixel_shader: &TPixelShader
+            pixel_shader: &'s TPixelShader
         ) {
-            for x in 0..indexes.len() {
-                let i = indexes[x];
+            for i in indexes {
+                let v0 = vertex_shader.process(&vertices[i.0]);
+                let v1 = vertex_shader.process(&vertices[i.1]);
+                let v2 = vertex_shader.process(&vertices[i.2]);
 
-                // TODO: reduce duplicated vertex processing
+                if !Self::is_vertex_finite(&v0)
+                    || !Self::is_vertex_finite(&v1)
+                    || !Self::is_vertex_finite(&v2)
+                    || self.does_triangle_clip_completely(v0, v1, v2) {
+                    continue;
+                }
+
+                let depth = (v0[3] + v1[3] + v2[3]) / 3.0;
+                let (v0, v1, v2) = self.transform_triangle(v0, v1, v2);
+
+                if !ordering_table.insert(depth, v0, v1, v2, pixel_shader) {
+                    break;
+                }
+            }
+    }
+
+    /// Draws every triangle queued in `ordering_table` back-to-front and
+    /// empties it.
+    pub fn flush_ordered<const TVECTOR_SIZE: usize>(
+        &mut self,
+        ordering_table: &mut OrderingTable<'_, '_, TVECTOR_SIZE>
+    ) {
+        ordering_table.for_each_back_to_front(|entry| {
+            self.draw_triangle(entry.pixel_shader, &entry.v0, &entry.v1, &entry.v2);
+        });
+
+        ordering_table.clear();
+    }
+
+    /// Same as `draw_mesh`, but triangles are queued in `transparent_queue`.
+    /// Call `flush_transparent` after all opaque geometry has been drawn.
+    pub fn draw_mesh_transparent
+        <'s,
+        TVertexShader: VertexShaderTrait<TVECTOR_IN_SIZE, TVECTOR_OUT_SIZE> + ?Sized,
+        TPixelShader: PixelShaderTrait<TVECTOR_OUT_SIZE>,
+        const TVECTOR_IN_SIZE: usize,
+        const TVECTOR_OUT_SIZE: usize>(
+            &mut self,
+            transparent_queue: &mut TransparentQueue<'_, 's, TVECTOR_OUT_SIZE>,
+            vertices: &[SVector<f32, TVECTOR_IN_SIZE>],
+            indexes: &[(usize, usize, usize)],
+            vertex_shader: &TVertexShader,
+            pixel_shader: &'s TPixelShader
+        ) {
+            for i in indexes {
                 let v0 = vertex_shader.process(&vertices[i.0]);
                 let v1 = vertex_shader.process(&vertices[i.1]);
                 let v2 = vertex_shader.process(&vertices[i.2]);
 
-                if self.does_triangle_clip_completely(v0, v1, v2) {
-                     continue;
-                } else {
-                    let (v0, v1, v2) = self.transform_triangle(v0, v1, v2);
+                if !Self::is_vertex_finite(&v0)
+                    || !Self::is_vertex_finite(&v1)
+                    || !Self::is_vertex_finite(&v2)
+                    || self.does_triangle_clip_completely(v0, v1, v2) {
+                    continue;
+                }
 
-                    self.draw_triangle(
-                        pixel_shader,
-                        &v0,
-                        &v1,
-                        &v2);
+                let depth = (v0[3] + v1[3] + v2[3]) / 3.0;
+                let (v0, v1, v2) = self.transform_triangle(v0, v1, v2);
+
+                if !transparent_queue.insert(depth, v0, v1, v2, pixel_shader) {
+                    break;
                 }
             }
     }
 
+    /// Draws every triangle queued in `transparent_queue` back-to-front with
+    /// depth writes off and empties it.
+    pub fn flush_transparent<const TVECTOR_SIZE: usize>(
+        &mut self,
+        transparent_queue: &mut TransparentQueue<'_, '_, TVECTOR_SIZE>
+    ) {
+        let depth_write = self.depth_write;
+        self.depth_write = false;
+
+        transparent_queue.for_each_back_to_front(|entry| {
+            self.draw_triangle(entry.pixel_shader, &entry.v0, &entry.v1, &entry.v2);
+        });
+
+        self.depth_write = depth_write;
+        transparent_queue.clear();
+    }
+
+    fn draw_clip_triangle<TPixelShader: PixelShaderTrait<TVECTOR_SIZE> + ?Sized, const TVECTOR_SIZE: usize>
+    (
+        &mut self,
+        pixel_shader: &TPixelShader,
+        v0: SVector<f32, TVECTOR_SIZE>,
+        v1: SVector<f32, TVECTOR_SIZE>,
+        v2: SVector<f32, TVECTOR_SIZE>
+    ) {
+        if !Self::is_vertex_finite(&v0) || !Self::is_vertex_finite(&v1) || !Self::is_vertex_finite(&v2) {
+            return;
+        }
+
+        if self.does_triangle_clip_completely(v0, v1, v2) {
+            return;
+        }
+
+        let (v0, v1, v2) = self.transform_triangle(v0, v1, v2);
+
+        self.draw_triangle(
+            pixel_shader,
+            &v0,
+            &v1,
+            &v2);
+    }
+
+    fn is_vertex_finite<const TVECTOR_SIZE: usize>(v: &SVector<f32, TVECTOR_SIZE>) -> bool {
+        v[3] != 0.0 && v.iter().all(|value| value.is_finite())
+    }
+
     fn does_triangle_clip_completely <const TVECTOR_SIZE: usize>
     (
         &self,
@@ -279,10 +838,10 @@ where
         let mut y = original_y * inv_w;
         let mut z = original_z * inv_w;
 
-        let px = self.screen.get_width() as f32 / 2.0;
+        let px = self.target_width() as f32 / 2.0;
         let ox = 0.0 + px;
 
-        let py = self.screen.get_height() as f32 / 2.0;
+        let py = self.target_height() as f32 / 2.0;
         let oy = 0.0 + py;
 
         x = px * x + ox;
@@ -300,164 +859,2252 @@ where
         transformed_vector
     }
 
+    /// Runs a full-screen pass over the colour buffer. Call it after the
+    /// last draw call and before `present`; passes run in call order.
+    pub fn post_process<TPass: PostProcessTrait>(&mut self, pass: &TPass) {
+        let width = self.target_width();
+        let height = self.target_height();
+        let scale = self.scale_factor();
+        let depth_buffer = self.depth_buffer
+            .as_deref()
+            .map(|depth_buffer| DepthBufferView::new(depth_buffer, width, height, scale));
+
+        pass.process(&mut self.screen, depth_buffer.as_ref());
+    }
+
     pub fn present(&mut self) {
         self.screen.update_screen().unwrap();
     }
 
-    fn draw_triangle<TPixelShader: PixelShaderTrait<VECTOR_SIZE>, const VECTOR_SIZE: usize>(
+    fn draw_triangle<TPixelShader: PixelShaderTrait<VECTOR_SIZE> + ?Sized, const VECTOR_SIZE: usize>(
         &mut self,
         pixel_shader: &TPixelShader,
         v0: &SVector<f32, VECTOR_SIZE>,
         v1: &SVector<f32, VECTOR_SIZE>,
         v2: &SVector<f32, VECTOR_SIZE>,
     ) {
-        let triangle = TriangleEquations::new(v2, v1, v0);
+        let mut triangle = TriangleEquations::new(v2, v1, v0);
+        let front_facing = triangle.area2 > 0.0;
 
-        if triangle.area2 <= 0.0 {
+        let culled = match self.cull_mode {
+            CullMode::None => triangle.area2 == 0.0,
+            CullMode::Front => triangle.area2 >= 0.0,
+            CullMode::Back => triangle.area2 <= 0.0
+        };
+
+        if culled {
             return
         }
 
+        // Back faces are drawn with the winding the edge tests expect
+        if !front_facing {
+            triangle = TriangleEquations::new(v0, v1, v2);
+        }
+
         let min_x = v0[0].min(v1[0]).min(v2[0]) as usize;
         let max_x = v0[0].max(v1[0]).max(v2[0]) as usize;
         let min_y = v0[1].min(v1[1]).min(v2[1]) as usize;
         let max_y = v0[1].max(v1[1]).max(v2[1]) as usize;
 
-        let min_x = min_x.max(0);
-        let max_x = max_x.min(self.screen.get_width() as usize);
-        let min_y = min_y.max(0);
-        let max_y = max_y.min(self.screen.get_height() as usize);
+        // Blocks are aligned to the screen so they match the coarse depth buffer
+        let min_x = min_x - min_x % BLOCK_SIZE;
+        let max_x = max_x.min(self.target_width() as usize);
+        let min_y = (min_y - min_y % BLOCK_SIZE).max(self.rows.0);
+        let max_y = max_y.min(self.target_height() as usize).min(self.rows.1);
+
+        let shading = TriangleShading::new(pixel_shader, &triangle, front_facing);
 
-        let step_size = BLOCK_SIZE - 1;
+        if self.rasterizer == Rasterizer::Scanline {
+            let vertices = [(v0[0], v0[1]), (v1[0], v1[1]), (v2[0], v2[1])];
+            self.draw_triangle_scanline(&shading, vertices, min_y, max_x, max_y);
+            return;
+        }
 
         let xm = max_x as usize;
         let ym = max_y as usize;
 
-        let step_size = step_size as f32;
-
         for x in (min_x..xm).step_by(BLOCK_SIZE) {
             for y in (min_y..ym).step_by(BLOCK_SIZE) {
                 let mut edge00 = EdgeData::new(&triangle, x as f32 + 1.0, y as f32 + 1.0);
 
-                let mut edge01 = edge00.clone();
-                edge01.step_y(&triangle, step_size);
-
-                let mut edge10 = edge00.clone();
-                edge10.step_x(&triangle, step_size);
-
-                let mut edge11 = edge01.clone();
-                edge11.step_x(&triangle, step_size);
-
-                let e00 = TriangleEdgeTest::new(&triangle, &edge00);
-                let e01 = TriangleEdgeTest::new(&triangle, &edge01);
-                let e10 = TriangleEdgeTest::new(&triangle, &edge10);
-                let e11 = TriangleEdgeTest::new(&triangle, &edge11);
-
-                let e00_all_true = e00.all_true();
-                let e01_all_true = e01.all_true();
-                let e10_all_true = e10.all_true();
-                let e11_all_true = e11.all_true();
-
-                let all_test_false =
-                    !e00_all_true
-                    && !e01_all_true
-                    && !e10_all_true
-                    && !e11_all_true;
-
-                let test_edges;
-
-                if all_test_false {
-                    test_edges =
-                    !e00.all_same()
-                    || !e01.all_same()
-                    || !e10.all_same()
-                    || !e11.all_same();
-                } else {
-                    let are_all_true =
-                    e00_all_true
-                    && e01_all_true
-                    && e10_all_true
-                    && e11_all_true;
-
-                    test_edges = !are_all_true;
-                }
-
-                if test_edges {
-                    self.draw_block::<TPixelShader, VECTOR_SIZE, true>(pixel_shader, &triangle, &mut edge00, x, y, max_x, max_y);
+                if Self::block_needs_edge_tests(&triangle, &edge00) {
+                    self.draw_block::<TPixelShader, VECTOR_SIZE, true>(&shading, &mut edge00, x, y, max_x, max_y);
                 } else {
-                    self.draw_block::<TPixelShader, VECTOR_SIZE, false>(pixel_shader, &triangle, &mut edge00, x, y, max_x, max_y);
+                    self.draw_block::<TPixelShader, VECTOR_SIZE, false>(&shading, &mut edge00, x, y, max_x, max_y);
                 }
             }
         }
     }
 
-    fn draw_block<TPixelShader: PixelShaderTrait<VECTOR_SIZE>, const VECTOR_SIZE: usize, const TEST_EDGES: bool>(
-        &mut self,
-        pixel_shader: &TPixelShader,
-        triangle: &TriangleEquations<VECTOR_SIZE>,
+    /// Whether the block starting at `edge00` is only partly covered, from
+    /// the edge tests at its four corners.
+    fn block_needs_edge_tests<const VECTOR_SIZE: usize>(triangle: &TriangleEquations<VECTOR_SIZE>, edge00: &EdgeData<VECTOR_SIZE>) -> bool {
+        let step_size = (BLOCK_SIZE - 1) as f32;
+
+        let mut edge01 = edge00.clone();
+        edge01.step_y(triangle, step_size);
+
+        let mut edge10 = edge00.clone();
+        edge10.step_x(triangle, step_size);
+
+        let mut edge11 = edge01.clone();
+        edge11.step_x(triangle, step_size);
+
+        let e00 = TriangleEdgeTest::new(triangle, edge00);
+        let e01 = TriangleEdgeTest::new(triangle, &edge01);
+        let e10 = TriangleEdgeTest::new(triangle, &edge10);
+        let e11 = TriangleEdgeTest::new(triangle, &edge11);
+
+        let e00_all_true = e00.all_true();
+        let e01_all_true = e01.all_true();
+        let e10_all_true = e10.all_true();
+        let e11_all_true = e11.all_true();
+
+        let all_test_false =
+            !e00_all_true
+            && !e01_all_true
+            && !e10_all_true
+            && !e11_all_true;
+
+        if all_test_false {
+            !e00.all_same()
+            || !e01.all_same()
+            || !e10.all_same()
+            || !e11.all_same()
+        } else {
+            let are_all_true =
+            e00_all_true
+            && e01_all_true
+            && e10_all_true
+            && e11_all_true;
+
+            !are_all_true
+        }
+    }
+
+    fn draw_block<TPixelShader: PixelShaderTrait<VECTOR_SIZE> + ?Sized, const VECTOR_SIZE: usize, const TEST_EDGES: bool>(
+        &mut self,
+        shading: &TriangleShading<TPixelShader, VECTOR_SIZE>,
+        edge: &mut EdgeData<VECTOR_SIZE>,
+        x: usize,
+        y: usize,
+        max_x: usize,
+        max_y: usize
+    ) {
+        let triangle = shading.triangle;
+        let mut test_depth = true;
+
+        if let Some((block_min, block_max)) = self.get_coarse_depth_value(x, y) {
+            if let Some((nearest, farthest)) = self.block_depth_range(triangle, edge, shading.w_slope) {
+                if nearest <= block_min {
+                    return;
+                }
+
+                test_depth = farthest <= block_max;
+            }
+        }
+
+        let columns = lane_mask(max_x.saturating_sub(x));
+        let mut row_start = PixelData::new(triangle, edge.x, edge.y);
+        let mut depth_written = false;
+
+        for y_pos in y..(y + BLOCK_SIZE).min(max_y) {
+            if !self.skip_row(y_pos) {
+                let mask = if TEST_EDGES {
+                    columns & coverage_mask(triangle, edge)
+                } else {
+                    columns
+                };
+
+                if mask != 0 && self.draw_row(shading, &row_start, mask, x, y_pos, test_depth) {
+                    depth_written = true;
+                }
+            }
+
+            row_start.step_y(triangle);
+
+            if TEST_EDGES {
+                edge.step_y_by_one(triangle);
+            }
+        }
+
+        if depth_written {
+            self.refresh_coarse_depth_value(x, y);
+        }
+    }
+
+    /// Pixel by pixel version of `draw_block`, the reference its packed rows
+    /// are tested against.
+    #[cfg(test)]
+    fn draw_block_per_pixel<TPixelShader: PixelShaderTrait<VECTOR_SIZE> + ?Sized, const VECTOR_SIZE: usize, const TEST_EDGES: bool>(
+        &mut self,
+        shading: &TriangleShading<TPixelShader, VECTOR_SIZE>,
         edge: &mut EdgeData<VECTOR_SIZE>,
         x: usize,
         y: usize,
         max_x: usize,
         max_y: usize
     ) {
-        let mut pixel_out = PixelData::new(&triangle, edge.x, edge.y);
+        let triangle = shading.triangle;
+        let mut row_start = PixelData::new(triangle, edge.x, edge.y);
+
+        for y_pos in y..(y + BLOCK_SIZE).min(max_y) {
+            let mut pixel = row_start.clone();
+            let mut pixel_edge = edge.clone();
+
+            for x_pos in x..(x + BLOCK_SIZE).min(max_x) {
+                if (!TEST_EDGES || pixel_edge.test(triangle)) && !self.skip_row(y_pos) {
+                    self.shade_pixel(shading, &pixel, x_pos, y_pos);
+                }
+
+                pixel.step_x(triangle);
+                pixel_edge.step_x_by_one(triangle);
+            }
+
+            row_start.step_y(triangle);
+            edge.step_y_by_one(triangle);
+        }
+    }
+
+    /// Edge-walking rasterizer. The long edge and the short edge beside it
+    /// are stepped one row at a time, only the pixels at the ends of each
+    /// span are checked with the edge equations so coverage and ties match
+    /// `Rasterizer::HalfSpace`.
+    fn draw_triangle_scanline<TPixelShader: PixelShaderTrait<VECTOR_SIZE> + ?Sized, const VECTOR_SIZE: usize>(
+        &mut self,
+        shading: &TriangleShading<TPixelShader, VECTOR_SIZE>,
+        mut vertices: [(f32, f32); 3],
+        min_y: usize,
+        max_x: usize,
+        max_y: usize
+    ) {
+        if max_x == 0 {
+            return;
+        }
+
+        let triangle = shading.triangle;
+
+        vertices.sort_unstable_by(|a, b| a.1.total_cmp(&b.1));
+        let [top, middle, bottom] = vertices;
+
+        // Pixels are sampled at (x + 1, y + 1), same as the block rasterizer
+        let first_y = (ceil_i32(top.1 - 1.0).max(0) as usize).max(min_y);
+        let mut long_edge = ScanEdge::new(top, bottom, first_y as f32 + 1.0);
+        let mut short_edge = ScanEdge::new(top, middle, first_y as f32 + 1.0);
+        let mut lower_half = false;
 
-        for y_pos in y..y + BLOCK_SIZE {
-            if y_pos >= max_y {
+        let mut span_min_x = max_x;
+        let mut span_max_x = 0;
+        let mut depth_written = false;
+
+        for y_pos in first_y..max_y {
+            let sample_y = y_pos as f32 + 1.0;
+
+            if sample_y > bottom.1 {
                 break;
             }
 
-            let mut pixel_in = pixel_out.clone();
-            let mut edge_in = if TEST_EDGES {
-                edge.clone()
-            } else {
-                EdgeData::empty()
+            if !lower_half && sample_y >= middle.1 {
+                short_edge = ScanEdge::new(middle, bottom, sample_y);
+                lower_half = true;
+            }
+
+            let left = long_edge.x.min(short_edge.x);
+            let right = long_edge.x.max(short_edge.x);
+
+            long_edge.step_y_by_one();
+            short_edge.step_y_by_one();
+
+            if self.skip_row(y_pos) {
+                continue;
+            }
+
+            let is_inside = |x: isize| {
+                EdgeData::new(triangle, x as f32 + 1.0, sample_y).test(triangle)
             };
 
-            for x_pos in x..x + BLOCK_SIZE {
-                if x_pos >= max_x {
-                    break;
-                }
+            let last_x = max_x as isize - 1;
+            let mut start = (ceil_i32(left - 1.0) as isize).clamp(0, max_x as isize);
+            let mut end = (ceil_i32(right - 1.0) as isize - 1).clamp(-1, last_x);
 
-                let d = 1.0/pixel_in.data[3];
-                if (!TEST_EDGES || edge_in.test(&triangle)) && self.depth_test(x_pos as u32, y_pos as u32, d) {
+            // The walked edges are rounded, so the ends may be a pixel off
+            while start > 0 && is_inside(start - 1) {
+                start -= 1;
+            }
+
+            while end < last_x && is_inside(end + 1) {
+                end += 1;
+            }
+
+            while start <= end && !is_inside(start) {
+                start += 1;
+            }
+
+            while end >= start && !is_inside(end) {
+                end -= 1;
+            }
+
+            if start > end {
+                continue;
+            }
 
-                    let pixel_value = pixel_shader.process(&pixel_in);
+            let (start, end) = (start as usize, end as usize);
 
-                    let r = (pixel_value.x * 255.0) as u8;
-                    let g = (pixel_value.y * 255.0) as u8;
-                    let b = (pixel_value.z * 255.0) as u8;
+            span_min_x = span_min_x.min(start);
+            span_max_x = span_max_x.max(end);
 
-                    self.screen.set_pixel(x_pos as u16, y_pos as u16,  r, g, b);
-                    self.set_depth_value(x_pos as u32, y_pos as u32, d);
+            let mut pixel = PixelData::new(triangle, start as f32 + 1.0, sample_y);
+
+            for x_pos in start..=end {
+                if self.shade_pixel(shading, &pixel, x_pos, y_pos) {
+                    depth_written = true;
                 }
 
-                pixel_in.step_x(&triangle);
+                pixel.step_x(triangle);
+            }
+        }
 
-                if TEST_EDGES {
-                    edge_in.step_x_by_one(&triangle);
+        if depth_written {
+            for y in (min_y..max_y).step_by(BLOCK_SIZE) {
+                for x in (span_min_x - span_min_x % BLOCK_SIZE..=span_max_x).step_by(BLOCK_SIZE) {
+                    self.refresh_coarse_depth_value(x, y);
                 }
             }
+        }
+    }
+
+    fn skip_row(&self, y: usize) -> bool {
+        // A scaled row covers rows of both fields
+        if !self.interlaced_render || self.scale_factor() != 1 {
+            return false;
+        }
+
+        match self.screen.get_interlace_field() {
+            Some(field) => !field.contains_row(y as u16),
+            None => false
+        }
+    }
+
+    /// Draws the pixels of a block row set in `mask`. Varyings, depths and
+    /// the depth test are computed for all lanes at once, then the passing
+    /// pixels are shaded. Returns whether any depth was written.
+    fn draw_row<TPixelShader: PixelShaderTrait<VECTOR_SIZE> + ?Sized, const VECTOR_SIZE: usize>(
+        &mut self,
+        shading: &TriangleShading<TPixelShader, VECTOR_SIZE>,
+        first: &PixelData<VECTOR_SIZE>,
+        mut mask: u8,
+        x: usize,
+        y: usize,
+        test_depth: bool
+    ) -> bool {
+        let depths = depth_lanes(shading.triangle, first, self.depth_bias_constant, self.depth_bias_slope_scale * shading.w_slope);
+
+        if test_depth && self.depth_test {
+            let start = (y - self.rows.0) * self.target_width() as usize + x;
+
+            if let Some(depth_buffer) = &self.depth_buffer {
+                let end = (start + LANES).min(depth_buffer.len());
+                mask &= depth_mask(&depths, &depth_buffer[start..end]);
+            }
+        }
 
-            pixel_out.step_y(&triangle);
+        if mask == 0 {
+            return false;
+        }
 
-            if TEST_EDGES {
-                edge.step_y_by_one(&triangle);
+        let row = PixelRow::new(shading.triangle, first);
+        let mut depth_written = false;
+
+        while mask != 0 {
+            let lane = mask.trailing_zeros() as usize;
+            mask &= mask - 1;
+
+            if self.write_pixel(shading, &row.pixel(lane), depths[lane], x + lane, y) {
+                depth_written = true;
+            }
+        }
+
+        depth_written
+    }
+
+    /// Depth tests, shades and writes a single covered pixel. Returns whether
+    /// its depth was written.
+    #[inline(always)]
+    fn shade_pixel<TPixelShader: PixelShaderTrait<VECTOR_SIZE> + ?Sized, const VECTOR_SIZE: usize>(
+        &mut self,
+        shading: &TriangleShading<TPixelShader, VECTOR_SIZE>,
+        pixel: &PixelData<VECTOR_SIZE>,
+        x: usize,
+        y: usize
+    ) -> bool {
+        let d = self.biased_depth(1.0/pixel.data[3], shading.w_slope);
+
+        if !self.depth_test(x as u32, y as u32, d) {
+            return false;
+        }
+
+        self.write_pixel(shading, pixel, d, x, y)
+    }
+
+    /// Shades and writes a pixel that passed the depth test. Returns whether
+    /// its depth was written.
+    #[inline(always)]
+    fn write_pixel<TPixelShader: PixelShaderTrait<VECTOR_SIZE> + ?Sized, const VECTOR_SIZE: usize>(
+        &mut self,
+        shading: &TriangleShading<TPixelShader, VECTOR_SIZE>,
+        pixel: &PixelData<VECTOR_SIZE>,
+        d: f32,
+        x: usize,
+        y: usize
+    ) -> bool {
+        let context = PixelContext {
+            x: x as u16,
+            y: y as u16,
+            depth: d,
+            front_facing: shading.front_facing,
+            ddx: &shading.triangle.ddx,
+            ddy: &shading.triangle.ddy,
+            material: None
+        };
+
+        let pixel_value = shading.pixel_shader.process(pixel, &context);
+
+        let mut rgb = (
+            (pixel_value.x * 255.0) as u8,
+            (pixel_value.y * 255.0) as u8,
+            (pixel_value.z * 255.0) as u8
+        );
+
+        if self.blend_mode != BlendMode::Opaque {
+            rgb = self.blend_mode.blend(rgb, self.get_target_pixel(x as u16, y as u16));
+        }
+
+        self.set_target_pixel(x as u16, y as u16, rgb.0, rgb.1, rgb.2);
+
+        if self.depth_write {
+            self.set_depth_value(x as u32, y as u32, d);
+            self.set_id_value(x as u32, y as u32);
+        }
+
+        self.depth_write
+    }
+
+    /// Nearest and farthest depth the triangle's plane reaches over the block
+    /// whose first sample is at `edge`, or None if it crosses w <= 0.
+    fn block_depth_range<const VECTOR_SIZE: usize>(
+        &self,
+        triangle: &TriangleEquations<VECTOR_SIZE>,
+        edge: &EdgeData<VECTOR_SIZE>,
+        w_slope: f32
+    ) -> Option<(f32, f32)> {
+        let w = &triangle.a_var[3];
+        let last = (BLOCK_SIZE - 1) as f32;
+
+        let corners = [
+            w.evaluate(edge.x, edge.y),
+            w.evaluate(edge.x + last, edge.y),
+            w.evaluate(edge.x, edge.y + last),
+            w.evaluate(edge.x + last, edge.y + last)
+        ];
+
+        let mut nearest = f32::MIN;
+        let mut farthest = f32::MAX;
+
+        for corner in corners {
+            // also rejects NaN
+            if corner.is_nan() || corner <= 0.0 {
+                return None;
+            }
+
+            let d = self.biased_depth(1.0 / corner, w_slope);
+            nearest = nearest.max(d);
+            farthest = farthest.min(d);
+        }
+
+        Some((nearest, farthest))
+    }
+
+    fn coarse_depth_index(&self, x: usize, y: usize) -> usize {
+        let blocks_x = (self.target_width() as usize).div_ceil(BLOCK_SIZE);
+        (((y - self.rows.0) / BLOCK_SIZE) * blocks_x + x / BLOCK_SIZE) * 2
+    }
+
+    fn get_coarse_depth_value(&self, x: usize, y: usize) -> Option<(f32, f32)> {
+        if !self.depth_test || self.depth_buffer.is_none() {
+            return None;
+        }
+
+        let index = self.coarse_depth_index(x, y);
+        self.coarse_depth_buffer.as_ref().map(|coarse| (coarse[index], coarse[index + 1]))
+    }
+
+    fn refresh_coarse_depth_value(&mut self, x: usize, y: usize) {
+        if self.coarse_depth_buffer.is_none() {
+            return;
+        }
+
+        let width = self.target_width() as usize;
+        let height = self.target_height() as usize;
+
+        let block_x = x - x % BLOCK_SIZE;
+        let block_y = y - y % BLOCK_SIZE;
+
+        let mut min = f32::MAX;
+        let mut max = f32::MIN;
+
+        if let Some(depth_buffer) = &self.depth_buffer {
+            for y_pos in block_y..(block_y + BLOCK_SIZE).min(height) {
+                for x_pos in block_x..(block_x + BLOCK_SIZE).min(width) {
+                    let depth = depth_buffer[(y_pos - self.rows.0) * width + x_pos];
+                    min = min.min(depth);
+                    max = max.max(depth);
+                }
             }
         }
+
+        let index = self.coarse_depth_index(block_x, block_y);
+
+        if let Some(coarse_depth_buffer) = &mut self.coarse_depth_buffer {
+            coarse_depth_buffer[index] = min;
+            coarse_depth_buffer[index + 1] = max;
+        }
+    }
+
+    fn scale_factor(&self) -> u16 {
+        if self.scene_buffer.is_some() {
+            self.render_scale.factor()
+        } else {
+            1
+        }
+    }
+
+    fn target_width(&self) -> u16 {
+        self.screen.get_width() / self.scale_factor()
+    }
+
+    fn target_height(&self) -> u16 {
+        self.screen.get_height() / self.scale_factor()
+    }
+
+    fn get_target_pixel(&self, x: u16, y: u16) -> (u8, u8, u8) {
+        let width = self.target_width() as usize;
+
+        match &self.scene_buffer {
+            Some(scene_buffer) if self.render_scale != RenderScale::Full => {
+                let index = (y as usize * width + x as usize) * 3;
+                (scene_buffer[index], scene_buffer[index + 1], scene_buffer[index + 2])
+            },
+            _ => self.screen.get_pixel(x, y)
+        }
+    }
+
+    fn set_target_pixel(&mut self, x: u16, y: u16, r: u8, g: u8, b: u8) {
+        let width = self.target_width() as usize;
+
+        match &mut self.scene_buffer {
+            Some(scene_buffer) if self.render_scale != RenderScale::Full => {
+                let index = (y as usize * width + x as usize) * 3;
+                scene_buffer[index] = r;
+                scene_buffer[index + 1] = g;
+                scene_buffer[index + 2] = b;
+            },
+            _ => self.screen.set_pixel(x, y, r, g, b)
+        }
+    }
+
+    fn biased_depth(&self, depth: f32, w_slope: f32) -> f32 {
+        // depth is 1/w, so its screen-space slope is |dw| * depth^2
+        depth
+            + self.depth_bias_constant
+            + self.depth_bias_slope_scale * w_slope * depth * depth
     }
 
     fn depth_test(&self, x: u32, y: u32, z: f32) -> bool {
-        !self.depth_test || z > self.get_depth_value(x, y)
+        if !self.depth_test {
+            return true;
+        }
+
+        match self.get_depth_value(x, y) {
+            Some(depth) => z > depth,
+            None => true
+        }
     }
 
-    fn get_depth_value(&self, x: u32, y: u32) -> f32 {
-        let index = y * self.screen.get_width() as u32 + x;
-        self.depth_buffer[index as usize]
+    fn get_depth_value(&self, x: u32, y: u32) -> Option<f32> {
+        let index = (y - self.rows.0 as u32) * self.target_width() as u32 + x;
+        self.depth_buffer.as_ref().map(|depth_buffer| depth_buffer[index as usize])
+    }
+
+    fn set_id_value(&mut self, x: u32, y: u32) {
+        let index = (y - self.rows.0 as u32) * self.target_width() as u32 + x;
+
+        if let Some(id_buffer) = &mut self.id_buffer {
+            id_buffer[index as usize] = self.object_id;
+        }
     }
 
     fn set_depth_value(&mut self, x: u32, y: u32, value: f32) {
-        let index = y * self.screen.get_width() as u32 + x;
-        self.depth_buffer[index as usize] = value;
+        let index = (y - self.rows.0 as u32) * self.target_width() as u32 + x;
+
+        if let Some(depth_buffer) = &mut self.depth_buffer {
+            depth_buffer[index as usize] = value;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::cell::RefCell;
+    use crate::ferret_3d::model::SubMesh;
+    use crate::fonts::font::GlyphRange;
+    use crate::fonts::glcd::GLCD;
+    use crate::fonts::tiny::TINY_FONT;
+    use crate::ferret_3d::texture::Texture;
+    use crate::sprite_options::SpriteOptions;
+    use crate::sprite_sheet::SpriteFrames;
+    use crate::sprite_sheet::SpriteSheet;
+    use crate::sprite_transform::SpriteTransform;
+    use crate::tilemap::Tile;
+    use crate::tilemap::TileAnimation;
+    use crate::tilemap::Tilemap;
+    use crate::tilemap::TilemapLayer;
+    use crate::tilemap::Tileset;
+    use crate::interlace_field::InterlaceField;
+    #[cfg(feature = "std")]
+    use crate::ferret_3d::parallel_queue::ParallelQueue;
+    #[cfg(feature = "std")]
+    use crate::screen_trait::FrameBuffer;
+    use crate::perspective_floor::FloorCamera;
+    use crate::perspective_floor::FloorSource;
+    use crate::post_process::color_grading::ColorGrading;
+    use crate::post_process::depth_outline::DepthOutline;
+    use crate::post_process::fade::Fade;
+    use crate::post_process::mosaic::Mosaic;
+    use crate::post_process::scanlines::Scanlines;
+    use nalgebra::Matrix4;
+    use nalgebra::Vector2;
+    use nalgebra::Vector3;
+    use nalgebra::Vector4;
+
+    const WIDTH: u16 = 16;
+    const HEIGHT: u16 = 16;
+
+    struct TestScreen {
+        pixels: [(u8, u8, u8); (WIDTH * HEIGHT) as usize],
+        field: Option<InterlaceField>
+    }
+
+    impl TestScreen {
+        fn new() -> Self {
+            Self {
+                pixels: [(0, 0, 0); (WIDTH * HEIGHT) as usize],
+                field: None
+            }
+        }
+    }
+
+    impl ScreenTrait for TestScreen {
+        fn get_width(&self) -> u16 { WIDTH }
+        fn get_height(&self) -> u16 { HEIGHT }
+
+        fn set_pixel(&mut self, x: u16, y: u16, r: u8, g: u8, b: u8) {
+            self.pixels[(y * WIDTH + x) as usize] = (r, g, b);
+        }
+
+        fn get_pixel(&self, x: u16, y: u16) -> (u8, u8, u8) {
+            self.pixels[(y * WIDTH + x) as usize]
+        }
+
+        fn clear(&mut self, r: u8, g: u8, b: u8) {
+            self.pixels = [(r, g, b); (WIDTH * HEIGHT) as usize];
+        }
+
+        fn update_screen(&mut self) -> Result<(), ()> {
+            Ok(())
+        }
+
+        fn get_interlace_field(&self) -> Option<InterlaceField> {
+            self.field
+        }
+    }
+
+    // Screen whose size isn't a multiple of the render scales
+    const ODD_SIZE: u16 = 18;
+
+    struct OddScreen {
+        pixels: [(u8, u8, u8); (ODD_SIZE * ODD_SIZE) as usize]
+    }
+
+    impl ScreenTrait for OddScreen {
+        fn get_width(&self) -> u16 { ODD_SIZE }
+        fn get_height(&self) -> u16 { ODD_SIZE }
+
+        fn set_pixel(&mut self, x: u16, y: u16, r: u8, g: u8, b: u8) {
+            self.pixels[(y * ODD_SIZE + x) as usize] = (r, g, b);
+        }
+
+        fn get_pixel(&self, x: u16, y: u16) -> (u8, u8, u8) {
+            self.pixels[(y * ODD_SIZE + x) as usize]
+        }
+
+        fn clear(&mut self, r: u8, g: u8, b: u8) {
+            self.pixels = [(r, g, b); (ODD_SIZE * ODD_SIZE) as usize];
+        }
+
+        fn update_screen(&mut self) -> Result<(), ()> {
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "std")]
+    struct FrameScreen {
+        pixels: [u8; (WIDTH * HEIGHT * 4) as usize]
+    }
+
+    #[cfg(feature = "std")]
+    impl ScreenTrait for FrameScreen {
+        fn get_width(&self) -> u16 { WIDTH }
+        fn get_height(&self) -> u16 { HEIGHT }
+
+        fn set_pixel(&mut self, x: u16, y: u16, r: u8, g: u8, b: u8) {
+            let index = (y * WIDTH + x) as usize * 4;
+            self.pixels[index..index + 4].copy_from_slice(&[r, g, b, 255]);
+        }
+
+        fn get_pixel(&self, x: u16, y: u16) -> (u8, u8, u8) {
+            let index = (y * WIDTH + x) as usize * 4;
+            (self.pixels[index], self.pixels[index + 1], self.pixels[index + 2])
+        }
+
+        fn clear(&mut self, r: u8, g: u8, b: u8) {
+            for pixel in self.pixels.chunks_exact_mut(4) {
+                pixel.copy_from_slice(&[r, g, b, 255]);
+            }
+        }
+
+        fn update_screen(&mut self) -> Result<(), ()> {
+            Ok(())
+        }
+
+        fn get_frame_buffer(&mut self) -> Option<FrameBuffer<'_>> {
+            Some(FrameBuffer {
+                pixels: &mut self.pixels,
+                bytes_per_pixel: 4
+            })
+        }
+    }
+
+    struct PassThroughShader;
+
+    impl VertexShaderTrait<4, 4> for PassThroughShader {
+        fn process(&self, v: &SVector<f32, 4>) -> SVector<f32, 4> {
+            *v
+        }
+    }
+
+    impl PixelShaderTrait<4> for PassThroughShader {
+        fn process(&self, _pixel_data: &PixelData<4>, _context: &PixelContext) -> Vector3<f32> {
+            Vector3::new(1.0, 1.0, 1.0)
+        }
+    }
+
+    fn triangle_vertices() -> [SVector<f32, 4>; 3] {
+        [
+            Vector4::new(-1.0, -1.0, 0.5, 1.0),
+            Vector4::new(-1.0, 1.0, 0.5, 1.0),
+            Vector4::new(1.0, -1.0, 0.5, 1.0)
+        ]
+    }
+
+    #[derive(Varyings)]
+    struct TestVaryings {
+        uv: nalgebra::Vector2<f32>,
+        #[position]
+        position: Vector4<f32>,
+        shade: f32
     }
-}
\ No newline at end of file
+
+    struct TypedShader;
+
+    impl TypedVertexShaderTrait for TypedShader {
+        type Input = TestVaryings;
+        type Output = TestVaryings;
+
+        fn process(&self, input: &TestVaryings) -> TestVaryings {
+            TestVaryings { ..*input }
+        }
+    }
+
+    impl TypedPixelShaderTrait for TypedShader {
+        type Input = TestVaryings;
+
+        fn process(&self, input: &TestVaryings, _context: &PixelContext) -> Vector3<f32> {
+            Vector3::new(input.shade, input.uv.x, input.uv.y)
+        }
+    }
+
+    #[test]
+    fn derived_varyings_put_position_first() {
+        let varyings = TestVaryings {
+            uv: nalgebra::Vector2::new(0.25, 0.75),
+            position: Vector4::new(1.0, 2.0, 3.0, 4.0),
+            shade: 0.5
+        };
+
+        let vector = varyings.to_vector();
+        assert_eq!(vector.as_slice(), &[1.0, 2.0, 3.0, 4.0, 0.25, 0.75, 0.5]);
+
+        let read = TestVaryings::from_vector(&vector);
+        assert_eq!(read.position, varyings.position);
+        assert_eq!(read.uv, varyings.uv);
+        assert_eq!(read.shade, varyings.shade);
+    }
+
+    #[test]
+    fn draw_mesh_typed_interpolates_named_fields() {
+        let mut depth_buffer = [0.0f32; (WIDTH * HEIGHT) as usize];
+        let mut graphics = FerretGraphics::new(TestScreen::new(), &mut depth_buffer);
+
+        let vertices = triangle_vertices().map(|position| TestVaryings {
+            uv: nalgebra::Vector2::new(0.0, 1.0),
+            position,
+            shade: 1.0
+        });
+
+        graphics.draw_mesh_typed(&vertices, &[(0, 1, 2)], &TypedShader, &TypedShader);
+
+        assert!(graphics.screen.pixels.contains(&(255, 0, 255)));
+    }
+
+    struct FacingShader;
+
+    impl PixelShaderTrait<4> for FacingShader {
+        fn process(&self, _pixel_data: &PixelData<4>, context: &PixelContext) -> Vector3<f32> {
+            if context.front_facing {
+                Vector3::new(1.0, 1.0, 1.0)
+            } else {
+                Vector3::new(1.0, 0.0, 0.0)
+            }
+        }
+    }
+
+    #[test]
+    fn back_faces_are_culled() {
+        let mut depth_buffer = [0.0f32; (WIDTH * HEIGHT) as usize];
+        let mut graphics = FerretGraphics::new(TestScreen::new(), &mut depth_buffer);
+
+        graphics.draw_mesh(&triangle_vertices(), &[(0, 2, 1)], &PassThroughShader, &FacingShader);
+        assert!(graphics.screen.pixels.iter().all(|pixel| *pixel == (0, 0, 0)));
+
+        graphics.draw_mesh(&triangle_vertices(), &[(0, 1, 2)], &PassThroughShader, &FacingShader);
+        assert!(graphics.screen.pixels.contains(&(255, 255, 255)));
+        assert!(!graphics.screen.pixels.contains(&(255, 0, 0)));
+    }
+
+    #[test]
+    fn cull_mode_none_shades_back_faces() {
+        let mut depth_buffer = [0.0f32; (WIDTH * HEIGHT) as usize];
+        let mut graphics = FerretGraphics::new(TestScreen::new(), &mut depth_buffer);
+
+        graphics.set_cull_mode(CullMode::Front);
+        graphics.draw_mesh(&triangle_vertices(), &[(0, 1, 2)], &PassThroughShader, &FacingShader);
+        assert!(graphics.screen.pixels.iter().all(|pixel| *pixel == (0, 0, 0)));
+
+        graphics.set_cull_mode(CullMode::None);
+        graphics.draw_mesh(&triangle_vertices(), &[(0, 2, 1)], &PassThroughShader, &FacingShader);
+        assert!(graphics.screen.pixels.contains(&(255, 0, 0)));
+        assert!(!graphics.screen.pixels.contains(&(255, 255, 255)));
+    }
+
+    struct InstanceShader;
+
+    impl InstancedVertexShaderTrait<4, 9> for InstanceShader {
+        fn process(&self, instance: &InstanceData, v: &SVector<f32, 4>) -> SVector<f32, 9> {
+            let position = instance.matrix * v;
+            let tint = instance.tint;
+            let uv = instance.uv_offset;
+
+            SVector::<f32, 9>::from_column_slice(&[
+                position.x, position.y, position.z, position.w,
+                tint.0, tint.1, tint.2,
+                uv.x, uv.y
+            ])
+        }
+    }
+
+    impl PixelShaderTrait<9> for InstanceShader {
+        fn process(&self, pixel_data: &PixelData<9>, _context: &PixelContext) -> Vector3<f32> {
+            // Snap the interpolated values, they are constant per instance
+            let snap = |value: f32, step: f32| if value > step * 0.5 { step } else { 0.0 };
+            let data = &pixel_data.data;
+
+            Vector3::new(snap(data[4], 1.0), snap(data[7], 0.5), snap(data[6], 1.0))
+        }
+    }
+
+    #[test]
+    fn draw_mesh_instanced_applies_instance_data() {
+        let vertices = [
+            Vector4::new(-1.0, -1.0, 0.5, 1.0),
+            Vector4::new(-1.0, 1.0, 0.5, 1.0),
+            Vector4::new(1.0, -1.0, 0.5, 1.0),
+            Vector4::new(1.0, 1.0, 0.5, 1.0)
+        ];
+        let indexes = [(0, 1, 2), (2, 1, 3)];
+
+        let quad = |x: f32| Matrix4::new_translation(&Vector3::new(x, 0.0, 0.0)) * Matrix4::new_nonuniform_scaling(&Vector3::new(0.25, 0.25, 1.0));
+        let mut left = InstanceData::new(quad(-0.5));
+        left.tint = Color(1.0, 0.0, 0.0);
+        let mut right = InstanceData::new(quad(0.5));
+        right.tint = Color(0.0, 0.0, 1.0);
+        right.uv_offset = Vector2::new(0.5, 0.0);
+
+        let mut depth_buffer = [0.0f32; (WIDTH * HEIGHT) as usize];
+        let mut graphics = FerretGraphics::new(TestScreen::new(), &mut depth_buffer);
+        graphics.draw_mesh_instanced(&vertices, &indexes, &[left, right], &InstanceShader, &InstanceShader);
+
+        // Each quad covers a quarter of the screen width around its center
+        for y in 7..9 {
+            for x in 3..5 {
+                assert_eq!(graphics.screen.get_pixel(x, y), (255, 0, 0));
+                assert_eq!(graphics.screen.get_pixel(x + 8, y), (0, 127, 255));
+            }
+        }
+
+        for y in 0..HEIGHT {
+            for x in [0, 7, 8, 15] {
+                assert_eq!(graphics.screen.get_pixel(x, y), (0, 0, 0));
+            }
+        }
+
+        for x in 0..WIDTH {
+            assert_eq!(graphics.screen.get_pixel(x, 2), (0, 0, 0));
+            assert_eq!(graphics.screen.get_pixel(x, 13), (0, 0, 0));
+        }
+    }
+
+    struct SolidShader(f32, f32, f32);
+
+    impl PixelShaderTrait<4> for SolidShader {
+        fn process(&self, _pixel_data: &PixelData<4>, _context: &PixelContext) -> Vector3<f32> {
+            Vector3::new(self.0, self.1, self.2)
+        }
+    }
+
+    #[test]
+    fn depth_bias_lets_coplanar_decal_win() {
+        let mut depth_buffer = [0.0f32; (WIDTH * HEIGHT) as usize];
+        let mut graphics = FerretGraphics::new(TestScreen::new(), &mut depth_buffer);
+        graphics.set_depth_test(true);
+        graphics.clear_depth_buffer(0.0);
+
+        let vertices = [
+            Vector4::new(-1.0, -1.0, 0.5, 1.0),
+            Vector4::new(-1.0, 1.0, 0.5, 1.0),
+            Vector4::new(1.0, -1.0, 0.5, 1.0),
+            Vector4::new(1.0, 1.0, 0.5, 1.0)
+        ];
+        let first = [(0, 1, 2)];
+        let second = [(2, 1, 3)];
+        let red = SolidShader(1.0, 0.0, 0.0);
+        let green = SolidShader(0.0, 1.0, 0.0);
+        let blue = SolidShader(0.0, 0.0, 1.0);
+
+        graphics.draw_mesh(&vertices, &[(0, 1, 2), (2, 1, 3)], &PassThroughShader, &PassThroughShader);
+        assert_eq!(graphics.screen.get_pixel(2, 2), (255, 255, 255));
+        assert_eq!(graphics.screen.get_pixel(13, 13), (255, 255, 255));
+
+        // Equal depth fails the test without bias
+        graphics.draw_mesh(&vertices, &first, &PassThroughShader, &red);
+        assert_eq!(graphics.screen.get_pixel(2, 2), (255, 255, 255));
+
+        graphics.set_depth_bias(0.01, 0.0);
+        graphics.draw_mesh(&vertices, &first, &PassThroughShader, &red);
+        assert_eq!(graphics.screen.get_pixel(2, 2), (255, 0, 0));
+
+        // The bias stays for later draws until it is reset
+        graphics.draw_mesh(&vertices, &second, &PassThroughShader, &green);
+        assert_eq!(graphics.screen.get_pixel(13, 13), (0, 255, 0));
+
+        graphics.set_depth_bias(0.0, 0.0);
+        graphics.draw_mesh(&vertices, &second, &PassThroughShader, &blue);
+        assert_eq!(graphics.screen.get_pixel(13, 13), (0, 255, 0));
+        graphics.draw_mesh(&vertices, &first, &PassThroughShader, &blue);
+        assert_eq!(graphics.screen.get_pixel(2, 2), (255, 0, 0));
+    }
+
+    struct DepthShader;
+
+    impl PixelShaderTrait<4> for DepthShader {
+        fn process(&self, _pixel_data: &PixelData<4>, context: &PixelContext) -> Vector3<f32> {
+            Vector3::new(context.depth, 0.0, 1.0)
+        }
+    }
+
+    fn draw_overlapping_triangles(graphics: &mut FerretGraphics<TestScreen>) {
+        let near = triangle_vertices();
+        let far = [
+            Vector4::new(-4.0, -4.0, 1.0, 2.0),
+            Vector4::new(-4.0, 8.0, 1.0, 2.0),
+            Vector4::new(8.0, -4.0, 1.0, 2.0)
+        ];
+
+        graphics.set_depth_test(true);
+        graphics.clear_depth_buffer(0.0);
+        graphics.draw_mesh(&near, &[(0, 1, 2)], &PassThroughShader, &DepthShader);
+        graphics.draw_mesh(&far, &[(0, 1, 2)], &PassThroughShader, &DepthShader);
+    }
+
+    #[test]
+    fn coarse_depth_buffer_does_not_change_output() {
+        let mut depth_buffer = [0.0f32; (WIDTH * HEIGHT) as usize];
+        let mut graphics = FerretGraphics::new(TestScreen::new(), &mut depth_buffer);
+        draw_overlapping_triangles(&mut graphics);
+        let expected = graphics.screen.pixels;
+
+        const COARSE_SIZE: usize = FerretGraphics::<TestScreen>::coarse_depth_buffer_size(WIDTH as usize, HEIGHT as usize);
+        let mut depth_buffer = [0.0f32; (WIDTH * HEIGHT) as usize];
+        let mut coarse_depth_buffer = [0.0f32; COARSE_SIZE];
+        let mut graphics = FerretGraphics::new(TestScreen::new(), &mut depth_buffer);
+        graphics.set_coarse_depth_buffer(Some(&mut coarse_depth_buffer));
+        draw_overlapping_triangles(&mut graphics);
+
+        assert!(graphics.screen.pixels == expected);
+        assert!(expected.iter().any(|pixel| pixel.0 == 255));
+        assert!(expected.iter().any(|pixel| pixel.0 == 127));
+    }
+
+    #[test]
+    fn scanline_rasterizer_matches_half_space_coverage() {
+        let vertices = [
+            Vector4::new(-0.9, -0.8, 0.5, 1.0),
+            Vector4::new(-0.3, 0.95, 0.5, 1.0),
+            Vector4::new(0.85, -0.1, 0.5, 1.0),
+            Vector4::new(-1.2, 0.7, 0.5, 1.0),
+            Vector4::new(1.3, 0.75, 0.5, 1.0),
+            Vector4::new(0.1, 0.2, 0.5, 1.0)
+        ];
+        let indexes = [(0, 1, 2), (3, 4, 5), (5, 4, 3)];
+
+        let mut depth_buffer = [0.0f32; (WIDTH * HEIGHT) as usize];
+        let mut graphics = FerretGraphics::new(TestScreen::new(), &mut depth_buffer);
+        graphics.draw_mesh(&vertices, &indexes, &PassThroughShader, &PassThroughShader);
+        let expected = graphics.screen.pixels;
+
+        let mut depth_buffer = [0.0f32; (WIDTH * HEIGHT) as usize];
+        let mut graphics = FerretGraphics::new(TestScreen::new(), &mut depth_buffer);
+        graphics.set_rasterizer(Rasterizer::Scanline);
+        graphics.draw_mesh(&vertices, &indexes, &PassThroughShader, &PassThroughShader);
+
+        assert!(graphics.screen.pixels == expected);
+        assert!(expected.contains(&(255, 255, 255)));
+    }
+
+    fn assert_scanline_covers_once<const N: usize, const M: usize>(points: [(f32, f32); N], indexes: [(usize, usize, usize); M]) {
+        let vertices = points.map(|(x, y)| Vector4::new(x, y, 0.5, 1.0));
+
+        let draw = |rasterizer| {
+            let mut depth_buffer = [0.0f32; (WIDTH * HEIGHT) as usize];
+            let mut graphics = FerretGraphics::new(TestScreen::new(), &mut depth_buffer);
+            graphics.set_cull_mode(CullMode::None);
+            graphics.set_blend_mode(BlendMode::Additive);
+            graphics.set_rasterizer(rasterizer);
+            graphics.draw_mesh(&vertices, &indexes, &PassThroughShader, &SolidShader(0.25, 0.25, 0.25));
+            graphics.screen.pixels
+        };
+
+        let expected = draw(Rasterizer::HalfSpace);
+        let pixels = draw(Rasterizer::Scanline);
+
+        // Any gap stays black, any overdraw adds up twice
+        assert!(pixels[0] != (0, 0, 0));
+        assert!(pixels.iter().all(|pixel| *pixel == pixels[0]));
+        assert!(pixels == expected);
+    }
+
+    #[test]
+    fn scanline_shared_edges_have_no_gaps_or_overdraw() {
+        // Irregular fan and ring reaching past the screen on every side
+        let fan = [
+            (0.13, -0.07),
+            (0.61, -0.02), (0.37, 0.49), (-0.21, 0.55), (-0.58, 0.11), (-0.43, -0.41), (0.05, -0.63), (0.52, -0.44),
+            (3.0, 0.2), (1.9, 2.4), (-0.8, 2.9), (-2.8, 1.1), (-2.6, -1.6), (-0.5, -3.0), (2.2, -2.1)
+        ];
+        let mut fan_indexes = [(0, 0, 0); 21];
+        for k in 0..7 {
+            let (inner, next_inner) = (1 + k, 1 + (k + 1) % 7);
+            let (outer, next_outer) = (8 + k, 8 + (k + 1) % 7);
+            fan_indexes[k * 3] = (0, inner, next_inner);
+            fan_indexes[k * 3 + 1] = (inner, outer, next_outer);
+            fan_indexes[k * 3 + 2] = (inner, next_outer, next_inner);
+        }
+
+        assert_scanline_covers_once(fan, fan_indexes);
+
+        // Grid whose edges and diagonals run exactly through pixel samples
+        let lines = [-1.25, -0.25, 0.5, 1.25];
+        let grid: [(f32, f32); 16] = core::array::from_fn(|i| (lines[i % 4], lines[i / 4]));
+        let mut grid_indexes = [(0, 0, 0); 18];
+        for cell in 0..9 {
+            let corner = cell / 3 * 4 + cell % 3;
+            grid_indexes[cell * 2] = (corner, corner + 1, corner + 4);
+            grid_indexes[cell * 2 + 1] = (corner + 1, corner + 5, corner + 4);
+        }
+
+        assert_scanline_covers_once(grid, grid_indexes);
+    }
+
+    #[test]
+    fn interlaced_render_only_shades_active_field() {
+        for rasterizer in [Rasterizer::HalfSpace, Rasterizer::Scanline] {
+            let mut depth_buffer = [0.0f32; (WIDTH * HEIGHT) as usize];
+            let mut screen = TestScreen::new();
+            screen.field = Some(InterlaceField::Odd);
+
+            let mut graphics = FerretGraphics::new(screen, &mut depth_buffer);
+            graphics.set_rasterizer(rasterizer);
+            graphics.set_interlaced_render(true);
+            graphics.draw_mesh(&triangle_vertices(), &[(0, 1, 2)], &PassThroughShader, &PassThroughShader);
+
+            for (index, pixel) in graphics.screen.pixels.iter().enumerate() {
+                let y = index as u16 / WIDTH;
+                if InterlaceField::Even.contains_row(y) {
+                    assert_eq!(*pixel, (0, 0, 0));
+                }
+            }
+
+            assert!(graphics.screen.pixels.contains(&(255, 255, 255)));
+        }
+    }
+
+    fn fill_gradient(screen: &mut TestScreen) {
+        for y in 0..HEIGHT {
+            for x in 0..WIDTH {
+                screen.set_pixel(x, y, (x * 10) as u8, (y * 10) as u8, 200);
+            }
+        }
+    }
+
+    #[test]
+    fn scanlines_darken_every_period_row() {
+        let mut graphics = FerretGraphics::new_without_depth_buffer(TestScreen::new());
+        graphics.screen.clear(200, 100, 50);
+        graphics.post_process(&Scanlines::new(0.5, 2));
+
+        for y in 0..HEIGHT {
+            let expected = if y % 2 == 1 { (100, 50, 25) } else { (200, 100, 50) };
+            assert!((0..WIDTH).all(|x| graphics.screen.get_pixel(x, y) == expected));
+        }
+
+        let mut aperture = Scanlines::new(0.5, 2);
+        aperture.aperture_mask = true;
+        graphics.screen.clear(200, 100, 40);
+        graphics.post_process(&aperture);
+
+        assert_eq!(graphics.screen.get_pixel(0, 0), (200, 75, 30));
+        assert_eq!(graphics.screen.get_pixel(1, 0), (150, 100, 30));
+        assert_eq!(graphics.screen.get_pixel(2, 0), (150, 75, 40));
+        assert_eq!(graphics.screen.get_pixel(0, 1), (100, 37, 15));
+    }
+
+    #[test]
+    fn mosaic_fills_blocks_with_top_left_pixel() {
+        let mut graphics = FerretGraphics::new_without_depth_buffer(TestScreen::new());
+        fill_gradient(&mut graphics.screen);
+        graphics.post_process(&Mosaic::new(1));
+        assert_eq!(graphics.screen.get_pixel(3, 5), (30, 50, 200));
+
+        graphics.post_process(&Mosaic::new(6));
+
+        for y in 0..HEIGHT {
+            for x in 0..WIDTH {
+                let (block_x, block_y) = (x / 6 * 6, y / 6 * 6);
+                let expected = ((block_x * 10) as u8, (block_y * 10) as u8, 200);
+                assert_eq!(graphics.screen.get_pixel(x, y), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn color_grading_looks_up_nearest_lut_entry() {
+        // 2x2x2 LUT that inverts every channel
+        let mut lut = [0u8; 2 * 2 * 2 * 3];
+        for (index, entry) in lut.chunks_mut(3).enumerate() {
+            entry[0] = 255 - (index & 1) as u8 * 255;
+            entry[1] = 255 - (index >> 1 & 1) as u8 * 255;
+            entry[2] = 255 - (index >> 2 & 1) as u8 * 255;
+        }
+
+        let mut graphics = FerretGraphics::new_without_depth_buffer(TestScreen::new());
+        graphics.screen.set_pixel(0, 0, 200, 30, 0);
+        graphics.screen.set_pixel(1, 0, 100, 160, 255);
+        graphics.post_process(&ColorGrading::new(&lut, 2));
+
+        assert_eq!(graphics.screen.get_pixel(0, 0), (0, 255, 255));
+        assert_eq!(graphics.screen.get_pixel(1, 0), (255, 0, 0));
+        assert_eq!(graphics.screen.get_pixel(5, 5), (255, 255, 255));
+
+        // A LUT too small for its size is ignored
+        graphics.post_process(&ColorGrading::new(&lut[..9], 2));
+        assert_eq!(graphics.screen.get_pixel(0, 0), (0, 255, 255));
+    }
+
+    #[test]
+    fn depth_outline_marks_depth_discontinuities() {
+        let mut depth_buffer = [0.5f32; (WIDTH * HEIGHT) as usize];
+        for (index, depth) in depth_buffer.iter_mut().enumerate() {
+            if index % WIDTH as usize >= 8 {
+                *depth = 0.25;
+            }
+        }
+
+        let outline = DepthOutline::new(0.1, Color(1.0, 0.0, 0.0));
+
+        let mut graphics = FerretGraphics::new_without_depth_buffer(TestScreen::new());
+        graphics.post_process(&outline);
+        assert!(graphics.screen.pixels.iter().all(|pixel| *pixel == (0, 0, 0)));
+
+        let mut graphics = FerretGraphics::new(TestScreen::new(), &mut depth_buffer);
+        graphics.post_process(&outline);
+
+        for y in 0..HEIGHT {
+            for x in 0..WIDTH {
+                let expected = if x == 7 { (255, 0, 0) } else { (0, 0, 0) };
+                assert_eq!(graphics.screen.get_pixel(x, y), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn fade_blends_towards_color() {
+        let mut graphics = FerretGraphics::new_without_depth_buffer(TestScreen::new());
+        fill_gradient(&mut graphics.screen);
+        let original = graphics.screen.pixels;
+
+        graphics.post_process(&Fade::new(Color(1.0, 1.0, 1.0), 0.0));
+        assert!(graphics.screen.pixels == original);
+
+        graphics.post_process(&Fade::new(Color(0.0, 0.0, 0.0), 0.5));
+        assert_eq!(graphics.screen.get_pixel(4, 6), (20, 30, 100));
+
+        graphics.post_process(&Fade::new(Color(1.0, 0.0, 1.0), 1.0));
+        assert!(graphics.screen.pixels.iter().all(|pixel| *pixel == (255, 0, 255)));
+    }
+
+    #[test]
+    fn half_render_scale_upscales_in_blocks() {
+        const SCENE_SIZE: usize = RenderScale::Half.scene_buffer_size(WIDTH as usize, HEIGHT as usize);
+
+        let mut depth_buffer = [0.0f32; (WIDTH * HEIGHT / 4) as usize];
+        let mut scene_buffer = [0u8; SCENE_SIZE];
+        let mut graphics = FerretGraphics::new(TestScreen::new(), &mut depth_buffer);
+        graphics.set_render_scale(RenderScale::Half, Some(&mut scene_buffer));
+        graphics.draw_mesh(&triangle_vertices(), &[(0, 1, 2)], &PassThroughShader, &PassThroughShader);
+
+        assert!(graphics.screen.pixels.iter().all(|pixel| *pixel == (0, 0, 0)));
+
+        graphics.resolve_scene();
+
+        for y in 0..HEIGHT {
+            for x in 0..WIDTH {
+                let pixel = graphics.screen.get_pixel(x, y);
+                assert_eq!(pixel, graphics.screen.get_pixel(x & !1, y & !1));
+            }
+        }
+
+        assert!(graphics.screen.pixels.contains(&(255, 255, 255)));
+    }
+
+    #[test]
+    fn ordering_table_replaces_depth_test() {
+        let mut depth_buffer = [0.0f32; (WIDTH * HEIGHT) as usize];
+        let mut graphics = FerretGraphics::new(TestScreen::new(), &mut depth_buffer);
+        draw_overlapping_triangles(&mut graphics);
+        let expected = graphics.screen.pixels;
+
+        let near = triangle_vertices();
+        let far = [
+            Vector4::new(-4.0, -4.0, 1.0, 2.0),
+            Vector4::new(-4.0, 8.0, 1.0, 2.0),
+            Vector4::new(8.0, -4.0, 1.0, 2.0)
+        ];
+
+        let mut buckets = [None; 8];
+        let mut entries = [None; 4];
+        let mut table = OrderingTable::new(&mut buckets, &mut entries, 4.0);
+        let mut graphics = FerretGraphics::new_without_depth_buffer(TestScreen::new());
+        graphics.draw_mesh_ordered(&mut table, &near, &[(0, 1, 2)], &PassThroughShader, &DepthShader);
+        graphics.draw_mesh_ordered(&mut table, &far, &[(0, 1, 2)], &PassThroughShader, &DepthShader);
+        assert_eq!(table.len(), 2);
+
+        graphics.flush_ordered(&mut table);
+        assert!(graphics.screen.pixels == expected);
+        assert!(table.is_empty());
+    }
+
+    #[test]
+    fn reduced_render_scale_covers_uneven_screen() {
+        const SCENE_SIZE: usize = RenderScale::Quarter.scene_buffer_size(ODD_SIZE as usize, ODD_SIZE as usize);
+
+        let mut depth_buffer = [0.0f32; SCENE_SIZE / 3];
+        let mut scene_buffer = [0u8; SCENE_SIZE];
+        let mut screen = OddScreen { pixels: [(0, 0, 0); (ODD_SIZE * ODD_SIZE) as usize] };
+        screen.clear(9, 9, 9);
+
+        let mut graphics = FerretGraphics::new(screen, &mut depth_buffer);
+        graphics.set_render_scale(RenderScale::Quarter, Some(&mut scene_buffer));
+        graphics.set_depth_test(true);
+        graphics.clear_depth_buffer(0.0);
+        graphics.draw_mesh(&triangle_vertices(), &[(0, 1, 2)], &PassThroughShader, &PassThroughShader);
+        graphics.resolve_scene();
+
+        // The leftover edge repeats the last scene row and column
+        for y in 0..ODD_SIZE {
+            for x in 0..ODD_SIZE {
+                let source = (x.min(15) & !3, y.min(15) & !3);
+                assert_eq!(graphics.screen.get_pixel(x, y), graphics.screen.get_pixel(source.0, source.1));
+            }
+        }
+
+        assert!(!graphics.screen.pixels.contains(&(9, 9, 9)));
+        assert!(graphics.screen.pixels.contains(&(255, 255, 255)));
+
+        // Reads the depth of the edge rows and columns without going out of bounds
+        graphics.post_process(&DepthOutline::new(0.1, Color(1.0, 0.0, 0.0)));
+        assert!(graphics.screen.pixels.contains(&(255, 0, 0)));
+    }
+
+    #[test]
+    fn transparent_queue_draws_back_to_front_without_depth_writes() {
+        let mut depth_buffer = [0.0f32; (WIDTH * HEIGHT) as usize];
+        let mut graphics = FerretGraphics::new(TestScreen::new(), &mut depth_buffer);
+        draw_overlapping_triangles(&mut graphics);
+        let expected = graphics.screen.pixels;
+
+        let near = triangle_vertices();
+        let far = [
+            Vector4::new(-4.0, -4.0, 1.0, 2.0),
+            Vector4::new(-4.0, 8.0, 1.0, 2.0),
+            Vector4::new(8.0, -4.0, 1.0, 2.0)
+        ];
+
+        let mut entries = [None; 4];
+        let mut queue = TransparentQueue::new(&mut entries);
+        let mut depth_buffer = [0.0f32; (WIDTH * HEIGHT) as usize];
+        let mut graphics = FerretGraphics::new(TestScreen::new(), &mut depth_buffer);
+        graphics.set_depth_test(true);
+        graphics.draw_mesh_transparent(&mut queue, &near, &[(0, 1, 2)], &PassThroughShader, &DepthShader);
+        graphics.draw_mesh_transparent(&mut queue, &far, &[(0, 1, 2)], &PassThroughShader, &DepthShader);
+        graphics.flush_transparent(&mut queue);
+
+        assert!(graphics.screen.pixels == expected);
+        assert!(queue.is_empty());
+        assert!(depth_buffer.iter().all(|depth| *depth == 0.0));
+    }
+
+    struct MaterialColorShader;
+
+    impl PixelShaderTrait<4> for MaterialColorShader {
+        fn process(&self, _pixel_data: &PixelData<4>, context: &PixelContext) -> Vector3<f32> {
+            let color = context.material.unwrap().color;
+            Vector3::new(color.0, color.1, color.2)
+        }
+    }
+
+    #[test]
+    fn draw_model_uses_material_per_sub_mesh() {
+        let vertices = [
+            Vector4::new(-1.0, -1.0, 0.5, 1.0),
+            Vector4::new(-1.0, 1.0, 0.5, 1.0),
+            Vector4::new(1.0, -1.0, 0.5, 1.0),
+            Vector4::new(1.0, 1.0, 0.5, 1.0)
+        ];
+        let indexes = [(0, 1, 2), (2, 1, 3)];
+        let sub_meshes = [
+            SubMesh { start: 0, count: 1, material: 0 },
+            SubMesh { start: 1, count: 1, material: 1 }
+        ];
+        let model = Model {
+            vertices: &vertices,
+            indexes: &indexes,
+            sub_meshes: &sub_meshes
+        };
+
+        let mut red = Material::new(&PassThroughShader, &MaterialColorShader);
+        red.properties.color = Color(1.0, 0.0, 0.0);
+        let mut green = Material::new(&PassThroughShader, &MaterialColorShader);
+        green.properties.color = Color(0.0, 1.0, 0.0);
+
+        let mut depth_buffer = [0.0f32; (WIDTH * HEIGHT) as usize];
+        let mut graphics = FerretGraphics::new(TestScreen::new(), &mut depth_buffer);
+        graphics.draw_model(&model, &[red, green]);
+
+        assert_eq!(graphics.screen.get_pixel(1, 1), (255, 0, 0));
+        assert_eq!(graphics.screen.get_pixel(WIDTH - 2, HEIGHT - 2), (0, 255, 0));
+
+        // Culling the front of green leaves its half empty
+        green.cull_mode = CullMode::Front;
+        let mut depth_buffer = [0.0f32; (WIDTH * HEIGHT) as usize];
+        let mut graphics = FerretGraphics::new(TestScreen::new(), &mut depth_buffer);
+        graphics.draw_model(&model, &[red, green]);
+
+        assert_eq!(graphics.screen.get_pixel(1, 1), (255, 0, 0));
+        assert_eq!(graphics.screen.get_pixel(WIDTH - 2, HEIGHT - 2), (0, 0, 0));
+    }
+
+    #[test]
+    fn draw_model_draws_blended_materials_last() {
+        // Glass covers the same pixels as the red triangle, nearer
+        let vertices = [
+            Vector4::new(-1.0, -1.0, 0.5, 1.0),
+            Vector4::new(-1.0, 1.0, 0.5, 1.0),
+            Vector4::new(1.0, -1.0, 0.5, 1.0),
+            Vector4::new(-0.5, -0.5, 0.25, 0.5),
+            Vector4::new(-0.5, 0.5, 0.25, 0.5),
+            Vector4::new(0.5, -0.5, 0.25, 0.5)
+        ];
+        let indexes = [(3, 4, 5), (0, 1, 2)];
+        let sub_meshes = [
+            SubMesh { start: 0, count: 1, material: 0 },
+            SubMesh { start: 1, count: 1, material: 1 }
+        ];
+        let model = Model {
+            vertices: &vertices,
+            indexes: &indexes,
+            sub_meshes: &sub_meshes
+        };
+
+        let mut glass = Material::new(&PassThroughShader, &MaterialColorShader);
+        glass.properties.color = Color(0.0, 0.0, 1.0);
+        glass.blend_mode = BlendMode::Alpha(128);
+        let mut red = Material::new(&PassThroughShader, &MaterialColorShader);
+        red.properties.color = Color(1.0, 0.0, 0.0);
+
+        let mut depth_buffer = [0.0f32; (WIDTH * HEIGHT) as usize];
+        let mut graphics = FerretGraphics::new(TestScreen::new(), &mut depth_buffer);
+        graphics.set_depth_test(true);
+        graphics.clear_depth_buffer(0.0);
+        graphics.set_depth_write(false);
+        graphics.draw_model(&model, &[glass, red]);
+
+        // The glass comes first in the model but is blended over the red
+        assert_eq!(graphics.screen.get_pixel(1, 1), (127, 0, 128));
+
+        // Depth writes are back to what they were before the call
+        let mut depth_buffer = [0.0f32; (WIDTH * HEIGHT) as usize];
+        let mut graphics = FerretGraphics::new(TestScreen::new(), &mut depth_buffer);
+        graphics.set_depth_write(false);
+        graphics.draw_model(&model, &[red, red]);
+        assert_eq!(graphics.get_depth_value(1, 1), Some(2.0));
+
+        graphics.draw_mesh(&vertices, &[(0, 1, 2)], &PassThroughShader, &SolidShader(0.0, 1.0, 0.0));
+        assert_eq!(graphics.get_depth_value(1, 1), Some(2.0));
+
+        graphics.set_depth_write(true);
+        graphics.set_blend_mode(BlendMode::Additive);
+        graphics.draw_mesh(&vertices, &[(0, 1, 2)], &PassThroughShader, &SolidShader(0.5, 0.0, 0.0));
+        assert_eq!(graphics.screen.get_pixel(1, 1), (127, 255, 0));
+        assert_eq!(graphics.get_depth_value(1, 1), Some(1.0));
+    }
+
+    #[test]
+    fn pick_returns_nearest_object_id() {
+        let mut depth_buffer = [0.0f32; (WIDTH * HEIGHT) as usize];
+        let mut id_buffer = [0u16; (WIDTH * HEIGHT) as usize];
+        let mut graphics = FerretGraphics::new(TestScreen::new(), &mut depth_buffer);
+        graphics.set_id_buffer(Some(&mut id_buffer));
+        graphics.set_depth_test(true);
+        graphics.clear_depth_buffer(0.0);
+
+        let far = [
+            Vector4::new(-4.0, -4.0, 1.0, 2.0),
+            Vector4::new(-4.0, 8.0, 1.0, 2.0),
+            Vector4::new(8.0, -4.0, 1.0, 2.0)
+        ];
+
+        graphics.set_object_id(1);
+        graphics.draw_mesh(&triangle_vertices(), &[(0, 1, 2)], &PassThroughShader, &PassThroughShader);
+        graphics.set_object_id(2);
+        graphics.draw_mesh(&far, &[(0, 1, 2)], &PassThroughShader, &PassThroughShader);
+
+        assert_eq!(graphics.pick(1, 1), Some(1));
+        assert_eq!(graphics.pick(WIDTH - 2, HEIGHT - 2), Some(2));
+        assert_eq!(graphics.pick(WIDTH, 0), None);
+
+        graphics.clear_depth_buffer(0.0);
+        assert_eq!(graphics.pick(1, 1), None);
+    }
+
+    // Records the varyings of every shaded pixel of an `OddScreen`
+    struct RecordingShader {
+        varyings: RefCell<[[u32; 4]; (ODD_SIZE * ODD_SIZE) as usize]>
+    }
+
+    impl PixelShaderTrait<4> for RecordingShader {
+        fn process(&self, pixel_data: &PixelData<4>, context: &PixelContext) -> Vector3<f32> {
+            self.varyings.borrow_mut()[(context.y * ODD_SIZE + context.x) as usize] = pixel_data.data.map(f32::to_bits);
+            Vector3::new(pixel_data.data[0] / ODD_SIZE as f32, pixel_data.data[1] / ODD_SIZE as f32, context.depth)
+        }
+    }
+
+    #[test]
+    fn packed_blocks_match_per_pixel_reference() {
+        // Overlapping triangles drawn far to near with perspective, the
+        // 18 pixel screen leaves partial blocks on its right and bottom
+        let triangles = [
+            [Vector4::new(-3.0, -3.0, 0.5, 1.0), Vector4::new(-3.0, 9.0, 0.5, 3.0), Vector4::new(9.0, -3.0, 0.5, 2.0)],
+            [Vector4::new(-0.9, -0.95, 0.5, 1.0), Vector4::new(-0.12, 0.78, 0.5, 0.6), Vector4::new(1.12, 0.08, 0.5, 0.8)],
+            [Vector4::new(-0.55, 0.3, 0.3, 0.5), Vector4::new(0.6, 0.375, 0.3, 0.5), Vector4::new(0.84, 0.686, 0.3, 0.7)],
+            [Vector4::new(0.28, -0.48, 0.3, 0.4), Vector4::new(0.4275, 0.495, 0.3, 0.45), Vector4::new(0.52, -0.4, 0.3, 0.4)]
+        ];
+
+        let draw = |packed: bool| {
+            let mut depth_buffer = [0.0f32; (ODD_SIZE * ODD_SIZE) as usize];
+            let mut id_buffer = [0u16; (ODD_SIZE * ODD_SIZE) as usize];
+            let shader = RecordingShader { varyings: RefCell::new([[0; 4]; (ODD_SIZE * ODD_SIZE) as usize]) };
+
+            let mut graphics = FerretGraphics::new(OddScreen { pixels: [(0, 0, 0); (ODD_SIZE * ODD_SIZE) as usize] }, &mut depth_buffer);
+            graphics.set_id_buffer(Some(&mut id_buffer));
+            graphics.set_depth_test(true);
+            graphics.set_depth_bias(0.001, 0.5);
+
+            for (id, vertices) in triangles.iter().enumerate() {
+                let (v0, v1, v2) = graphics.transform_triangle(vertices[0], vertices[1], vertices[2]);
+                let mut triangle = TriangleEquations::new(&v2, &v1, &v0);
+                if triangle.area2 <= 0.0 {
+                    triangle = TriangleEquations::new(&v0, &v1, &v2);
+                }
+
+                let shading = TriangleShading::new(&shader, &triangle, true);
+                graphics.set_object_id(id as u16 + 1);
+
+                for y in (0..ODD_SIZE as usize).step_by(BLOCK_SIZE) {
+                    for x in (0..ODD_SIZE as usize).step_by(BLOCK_SIZE) {
+                        let mut edge = EdgeData::new(&triangle, x as f32 + 1.0, y as f32 + 1.0);
+                        let max = ODD_SIZE as usize;
+
+                        match (packed, FerretGraphics::<OddScreen>::block_needs_edge_tests(&triangle, &edge)) {
+                            (true, true) => graphics.draw_block::<_, 4, true>(&shading, &mut edge, x, y, max, max),
+                            (true, false) => graphics.draw_block::<_, 4, false>(&shading, &mut edge, x, y, max, max),
+                            (false, true) => graphics.draw_block_per_pixel::<_, 4, true>(&shading, &mut edge, x, y, max, max),
+                            (false, false) => graphics.draw_block_per_pixel::<_, 4, false>(&shading, &mut edge, x, y, max, max)
+                        }
+                    }
+                }
+            }
+
+            let pixels = graphics.screen.pixels;
+            (pixels, depth_buffer.map(f32::to_bits), id_buffer, shader.varyings.into_inner())
+        };
+
+        let expected = draw(false);
+        let result = draw(true);
+
+        assert!(result.0 == expected.0);
+        assert!(result.1 == expected.1);
+        assert!(result.2 == expected.2);
+        assert!(result.3 == expected.3);
+
+        // Every triangle is visible somewhere, including the partial blocks
+        for id in 1..=triangles.len() as u16 {
+            assert!(expected.2.contains(&id), "{}", id);
+        }
+
+        assert!((0..ODD_SIZE as usize).any(|y| expected.2[y * ODD_SIZE as usize + 17] != 0));
+        assert!(expected.2[17 * ODD_SIZE as usize..].iter().any(|id| *id != 0));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn parallel_draw_matches_single_threaded() {
+        for rasterizer in [Rasterizer::HalfSpace, Rasterizer::Scanline] {
+            let mut depth_buffer = [0.0f32; (WIDTH * HEIGHT) as usize];
+            let mut graphics = FerretGraphics::new(TestScreen::new(), &mut depth_buffer);
+            graphics.set_rasterizer(rasterizer);
+            draw_overlapping_triangles(&mut graphics);
+            let expected = graphics.screen.pixels;
+            let expected_depth = depth_buffer;
+
+            let near = triangle_vertices();
+            let far = [
+                Vector4::new(-4.0, -4.0, 1.0, 2.0),
+                Vector4::new(-4.0, 8.0, 1.0, 2.0),
+                Vector4::new(8.0, -4.0, 1.0, 2.0)
+            ];
+
+            for threads in 1..4 {
+                let mut depth_buffer = [0.0f32; (WIDTH * HEIGHT) as usize];
+                let mut graphics = FerretGraphics::new(TestScreen::new(), &mut depth_buffer);
+                graphics.set_rasterizer(rasterizer);
+                graphics.set_depth_test(true);
+                graphics.draw_mesh_parallel(&near, &[(0, 1, 2)], &PassThroughShader, &DepthShader, threads);
+                graphics.draw_mesh_parallel(&far, &[(0, 1, 2)], &PassThroughShader, &DepthShader, threads);
+
+                assert!(graphics.screen.pixels == expected);
+                assert_eq!(depth_buffer, expected_depth);
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn flush_parallel_matches_serial_draws() {
+        const COARSE_SIZE: usize = FerretGraphics::<FrameScreen>::coarse_depth_buffer_size(WIDTH as usize, HEIGHT as usize);
+
+        let near = triangle_vertices();
+        let far = [
+            Vector4::new(-4.0, -4.0, 1.0, 2.0),
+            Vector4::new(-4.0, 8.0, 1.0, 2.0),
+            Vector4::new(8.0, -4.0, 1.0, 2.0)
+        ];
+
+        let draw = |threads: Option<usize>| {
+            let mut depth_buffer = [0.0f32; (WIDTH * HEIGHT) as usize];
+            let mut coarse_depth_buffer = [0.0f32; COARSE_SIZE];
+            let mut id_buffer = [0u16; (WIDTH * HEIGHT) as usize];
+            let mut screen = FrameScreen { pixels: [0; (WIDTH * HEIGHT * 4) as usize] };
+            screen.clear(0, 0, 64);
+
+            let mut graphics = FerretGraphics::new(screen, &mut depth_buffer);
+            graphics.set_coarse_depth_buffer(Some(&mut coarse_depth_buffer));
+            graphics.set_id_buffer(Some(&mut id_buffer));
+            graphics.set_depth_test(true);
+            graphics.clear_depth_buffer(0.0);
+
+            let mut parallel_queue = ParallelQueue::new();
+
+            // Every mesh keeps the state it was queued with
+            for (object_id, blend_mode, vertices) in [(1, BlendMode::Opaque, &far), (2, BlendMode::Additive, &near), (3, BlendMode::Alpha(128), &far)] {
+                graphics.set_object_id(object_id);
+                graphics.set_blend_mode(blend_mode);
+                graphics.set_depth_write(object_id != 3);
+
+                match threads {
+                    Some(_) => graphics.queue_mesh_parallel(&mut parallel_queue, vertices, &[(0, 1, 2)], &PassThroughShader, &DepthShader),
+                    None => graphics.draw_mesh(vertices, &[(0, 1, 2)], &PassThroughShader, &DepthShader)
+                }
+            }
+
+            graphics.set_blend_mode(BlendMode::Opaque);
+            graphics.set_depth_write(true);
+
+            if let Some(threads) = threads {
+                assert_eq!(parallel_queue.len(), 3);
+                graphics.flush_parallel(&mut parallel_queue, threads);
+                assert!(parallel_queue.is_empty());
+            }
+
+            assert!(graphics.blend_mode == BlendMode::Opaque && graphics.depth_write);
+
+            let pixels = graphics.screen.pixels;
+            (pixels, depth_buffer, coarse_depth_buffer, id_buffer)
+        };
+
+        let expected = draw(None);
+        assert!(expected.3.contains(&1) && expected.3.contains(&2) && !expected.3.contains(&3));
+
+        for threads in 1..4 {
+            assert!(draw(Some(threads)) == expected);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    struct ThreadShader {
+        threads: std::sync::Mutex<Vec<std::thread::ThreadId>>
+    }
+
+    #[cfg(feature = "std")]
+    impl PixelShaderTrait<4> for ThreadShader {
+        fn process(&self, _pixel_data: &PixelData<4>, _context: &PixelContext) -> Vector3<f32> {
+            let mut threads = self.threads.lock().unwrap();
+            let thread = std::thread::current().id();
+
+            if !threads.contains(&thread) {
+                threads.push(thread);
+            }
+
+            Vector3::new(1.0, 1.0, 1.0)
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn flush_parallel_draws_frame_buffer_bands_on_worker_threads() {
+        let vertices = [
+            Vector4::new(-1.0, -1.0, 0.5, 1.0),
+            Vector4::new(-1.0, 4.0, 0.5, 1.0),
+            Vector4::new(4.0, -1.0, 0.5, 1.0)
+        ];
+
+        for threads in 1..4 {
+            let mut depth_buffer = [0.0f32; (WIDTH * HEIGHT) as usize];
+            let mut graphics = FerretGraphics::new(FrameScreen { pixels: [0; (WIDTH * HEIGHT * 4) as usize] }, &mut depth_buffer);
+            let shader = ThreadShader { threads: std::sync::Mutex::new(Vec::new()) };
+            let mut parallel_queue = ParallelQueue::new();
+
+            graphics.queue_mesh_parallel(&mut parallel_queue, &vertices, &[(0, 1, 2)], &PassThroughShader, &shader);
+            graphics.flush_parallel(&mut parallel_queue, threads);
+
+            assert!(graphics.screen.pixels.chunks_exact(4).all(|pixel| pixel[..3] == [255, 255, 255]));
+
+            // A 16 pixel high screen splits into at most two 8 row bands
+            let shaded_by = shader.threads.into_inner().unwrap();
+            let caller = std::thread::current().id();
+
+            if threads == 1 {
+                assert!(shaded_by == [caller]);
+            } else {
+                assert_eq!(shaded_by.len(), 2);
+                assert!(!shaded_by.contains(&caller));
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn flush_parallel_writes_scene_buffer_bands() {
+        const SCENE_SIZE: usize = RenderScale::Half.scene_buffer_size(ODD_SIZE as usize, ODD_SIZE as usize);
+        const SCENE_PIXELS: usize = ((ODD_SIZE / 2) * (ODD_SIZE / 2)) as usize;
+
+        let draw = |threads: Option<usize>| {
+            let mut depth_buffer = [0.0f32; SCENE_PIXELS];
+            let mut scene_buffer = [0u8; SCENE_SIZE];
+            let mut graphics = FerretGraphics::new(OddScreen { pixels: [(0, 0, 0); (ODD_SIZE * ODD_SIZE) as usize] }, &mut depth_buffer);
+            graphics.set_render_scale(RenderScale::Half, Some(&mut scene_buffer));
+            graphics.set_depth_test(true);
+
+            // Covers all 9 rows, split into a band of 8 and a band of 1
+            let vertices = [
+                Vector4::new(-4.0, -4.0, 1.0, 2.0),
+                Vector4::new(-4.0, 12.0, 1.0, 2.0),
+                Vector4::new(12.0, -4.0, 1.0, 2.0)
+            ];
+
+            match threads {
+                Some(threads) => graphics.draw_mesh_parallel(&vertices, &[(0, 1, 2)], &PassThroughShader, &DepthShader, threads),
+                None => graphics.draw_mesh(&vertices, &[(0, 1, 2)], &PassThroughShader, &DepthShader)
+            }
+
+            (scene_buffer, depth_buffer)
+        };
+
+        let expected = draw(None);
+        assert!(expected.1[SCENE_PIXELS - 1] != 0.0);
+
+        for threads in 1..4 {
+            let result = draw(Some(threads));
+            assert!(result == expected);
+        }
+    }
+
+    #[test]
+    fn fill_polygon_matches_clipped_fill_rect() {
+        let white = Color(1.0, 1.0, 1.0);
+
+        let mut graphics = FerretGraphics::new_without_depth_buffer(TestScreen::new());
+        graphics.fill_rect(Rect::new(-4, 3, 9, 40), white);
+        let expected = graphics.screen.pixels;
+
+        let mut graphics = FerretGraphics::new_without_depth_buffer(TestScreen::new());
+        graphics.fill_polygon(&[(-4, 3), (9, 3), (9, 40), (-4, 40)], white);
+
+        assert!(graphics.screen.pixels == expected);
+        assert_eq!(expected.iter().filter(|pixel| **pixel == (255, 255, 255)).count(), 9 * 13);
+    }
+
+    fn lit_pixels(screen: &TestScreen) -> usize {
+        screen.pixels.iter().filter(|pixel| **pixel != (0, 0, 0)).count()
+    }
+
+    #[test]
+    fn lines_use_a_square_brush() {
+        let white = Color(1.0, 1.0, 1.0);
+
+        let mut graphics = FerretGraphics::new_without_depth_buffer(TestScreen::new());
+        graphics.draw_line(0, 0, 15, 15, 1, white);
+        assert_eq!(lit_pixels(&graphics.screen), 16);
+        assert_eq!(graphics.screen.get_pixel(7, 7), (255, 255, 255));
+
+        let mut graphics = FerretGraphics::new_without_depth_buffer(TestScreen::new());
+        graphics.draw_line(2, 8, 12, 8, 3, white);
+        assert_eq!(lit_pixels(&graphics.screen), 3 * 13);
+
+        for (x, y) in [(1, 7), (13, 9), (7, 8)] {
+            assert_eq!(graphics.screen.get_pixel(x, y), (255, 255, 255));
+        }
+
+        for (x, y) in [(0, 8), (14, 8), (7, 6), (7, 10)] {
+            assert_eq!(graphics.screen.get_pixel(x, y), (0, 0, 0));
+        }
+    }
+
+    #[test]
+    fn lines_are_clipped_to_the_screen() {
+        let white = Color(1.0, 1.0, 1.0);
+
+        let mut graphics = FerretGraphics::new_without_depth_buffer(TestScreen::new());
+        graphics.draw_line(i32::MIN, i32::MIN, i32::MAX, i32::MAX, 1, white);
+        assert_eq!(lit_pixels(&graphics.screen), 16);
+
+        for x in 0..16 {
+            assert_eq!(graphics.screen.get_pixel(x, x), (255, 255, 255));
+        }
+
+        let mut graphics = FerretGraphics::new_without_depth_buffer(TestScreen::new());
+        graphics.draw_line(i32::MAX, 5, i32::MIN, 5, 3, white);
+        assert_eq!(lit_pixels(&graphics.screen), 3 * 16);
+
+        // The brush reaches onto the screen from a line just above it
+        let mut graphics = FerretGraphics::new_without_depth_buffer(TestScreen::new());
+        graphics.draw_line(-100, -1, 100, -1, 3, white);
+        assert_eq!(lit_pixels(&graphics.screen), 16);
+        assert_eq!(graphics.screen.get_pixel(8, 0), (255, 255, 255));
+
+        let mut graphics = FerretGraphics::new_without_depth_buffer(TestScreen::new());
+        graphics.draw_line(-100, 20, 100, 40, 3, white);
+        graphics.draw_line(i32::MIN, 0, -2, i32::MAX, 1, white);
+        assert_eq!(lit_pixels(&graphics.screen), 0);
+    }
+
+    #[test]
+    fn circles_and_ellipses_fill_their_outline() {
+        let white = Color(1.0, 1.0, 1.0);
+
+        let mut outline = FerretGraphics::new_without_depth_buffer(TestScreen::new());
+        outline.draw_circle(8, 8, 5, white);
+        let mut filled = FerretGraphics::new_without_depth_buffer(TestScreen::new());
+        filled.fill_circle(8, 8, 5, white);
+
+        for (x, y) in [(13, 8), (3, 8), (8, 3), (8, 13)] {
+            assert_eq!(outline.screen.get_pixel(x, y), (255, 255, 255));
+        }
+
+        assert_eq!(outline.screen.get_pixel(8, 8), (0, 0, 0));
+        assert_eq!(filled.screen.get_pixel(8, 8), (255, 255, 255));
+        assert_eq!(filled.screen.get_pixel(12, 12), (0, 0, 0));
+        assert!(outline.screen.pixels.iter().zip(&filled.screen.pixels).all(|(a, b)| *a == (0, 0, 0) || a == b));
+
+        let mut outline = FerretGraphics::new_without_depth_buffer(TestScreen::new());
+        outline.draw_ellipse(8, 8, 6, 3, white);
+        let mut filled = FerretGraphics::new_without_depth_buffer(TestScreen::new());
+        filled.fill_ellipse(8, 8, 6, 3, white);
+
+        for (x, y) in [(14, 8), (2, 8), (8, 5), (8, 11)] {
+            assert_eq!(outline.screen.get_pixel(x, y), (255, 255, 255));
+        }
+
+        assert_eq!(outline.screen.get_pixel(8, 8), (0, 0, 0));
+        assert_eq!(filled.screen.get_pixel(8, 8), (255, 255, 255));
+        assert_eq!(filled.screen.get_pixel(14, 5), (0, 0, 0));
+        assert!(outline.screen.pixels.iter().zip(&filled.screen.pixels).all(|(a, b)| *a == (0, 0, 0) || a == b));
+    }
+
+    #[test]
+    fn degenerate_ellipses_draw_lines() {
+        let white = Color(1.0, 1.0, 1.0);
+
+        for (radius_x, radius_y, count) in [(4, 0, 9), (0, 3, 7), (0, 0, 1)] {
+            let mut outline = FerretGraphics::new_without_depth_buffer(TestScreen::new());
+            outline.draw_ellipse(8, 8, radius_x, radius_y, white);
+            let mut filled = FerretGraphics::new_without_depth_buffer(TestScreen::new());
+            filled.fill_ellipse(8, 8, radius_x, radius_y, white);
+
+            assert_eq!(lit_pixels(&outline.screen), count);
+            assert!(outline.screen.pixels == filled.screen.pixels);
+            assert_eq!(outline.screen.get_pixel(8 + radius_x, 8 + radius_y), (255, 255, 255));
+            assert_eq!(outline.screen.get_pixel(8 - radius_x, 8 - radius_y), (255, 255, 255));
+        }
+    }
+
+    #[test]
+    fn rounded_rect_leaves_corners_empty() {
+        let mut graphics = FerretGraphics::new_without_depth_buffer(TestScreen::new());
+        graphics.fill_rounded_rect(Rect::new(0, 0, 16, 16), 4, Color(1.0, 1.0, 1.0));
+
+        for (x, y) in [(0, 0), (15, 0), (0, 15), (15, 15)] {
+            assert_eq!(graphics.screen.get_pixel(x, y), (0, 0, 0));
+        }
+
+        for (x, y) in [(8, 0), (0, 8), (15, 8), (8, 15), (8, 8)] {
+            assert_eq!(graphics.screen.get_pixel(x, y), (255, 255, 255));
+        }
+    }
+
+    #[test]
+    fn draw_sprite_flips_keys_and_clips() {
+        // 2x2 texture: red, green / blue, black
+        let data = [255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0];
+        let texture = Texture::new(&data, 2, 2);
+
+        let mut options = SpriteOptions::new();
+        options.flip_x = true;
+        options.color_key = Some((0, 0, 255));
+
+        let mut graphics = FerretGraphics::new_without_depth_buffer(TestScreen::new());
+        graphics.clear_color_buffer(Color(1.0, 1.0, 1.0));
+        graphics.draw_sprite(&texture, Rect::new(0, 0, 2, 2), 0, 0, &options);
+
+        assert_eq!(graphics.screen.get_pixel(0, 0), (0, 255, 0));
+        assert_eq!(graphics.screen.get_pixel(1, 0), (255, 0, 0));
+        assert_eq!(graphics.screen.get_pixel(0, 1), (0, 0, 0));
+        assert_eq!(graphics.screen.get_pixel(1, 1), (255, 255, 255));
+
+        graphics.draw_sprite(&texture, Rect::new(0, 0, 2, 2), WIDTH as i32 - 1, -1, &SpriteOptions::new());
+        assert_eq!(graphics.screen.get_pixel(WIDTH - 1, 0), (0, 0, 255));
+    }
+
+    #[test]
+    fn draw_sprite_frame_places_pivot() {
+        // 4x2 texture holding two 2x2 cells, the second one green
+        let mut data = [0u8; 4 * 2 * 3];
+        for y in 0..2 {
+            for x in 2..4 {
+                data[(y * 4 + x) * 3 + 1] = 255;
+            }
+        }
+
+        let sheet = SpriteSheet::new(
+            Texture::new(&data, 4, 2),
+            SpriteFrames::Grid { cell_width: 2, cell_height: 2, pivot: (1, 1) });
+
+        assert_eq!(sheet.frame_count(), 2);
+        assert_eq!(sheet.frame(1).unwrap().rect, Rect::new(2, 0, 4, 2));
+        assert!(sheet.frame(2).is_none());
+
+        let mut graphics = FerretGraphics::new_without_depth_buffer(TestScreen::new());
+        graphics.draw_sprite_frame(&sheet, 1, 5, 5, &SpriteOptions::new());
+
+        assert_eq!(graphics.screen.get_pixel(4, 4), (0, 255, 0));
+        assert_eq!(graphics.screen.get_pixel(5, 5), (0, 255, 0));
+        assert_eq!(graphics.screen.get_pixel(6, 6), (0, 0, 0));
+    }
+
+    #[test]
+    fn draw_tilemap_scrolls_layers_with_parallax() {
+        // 2x1 tileset of 4x4 tiles: red, green
+        let mut data = [0u8; 8 * 4 * 3];
+        for y in 0..4 {
+            for x in 0..8 {
+                data[(y * 8 + x) * 3 + if x < 4 { 0 } else { 1 }] = 255;
+            }
+        }
+
+        let tileset = Tileset {
+            texture: Texture::new(&data, 8, 4),
+            tile_width: 4,
+            tile_height: 4,
+            color_key: Some((0, 255, 0)),
+            animations: &[TileAnimation { first: 0, count: 2, frame_millis: 100 }]
+        };
+
+        let tiles = [Some(Tile::new(0)), None, Some(Tile::new(0)), Some(Tile::new(1))];
+        let mut layer = TilemapLayer::new(&tiles, 4, 1);
+        layer.parallax = (0.5, 1.0);
+        let layers = [layer];
+        let tilemap = Tilemap { tileset, layers: &layers };
+
+        let mut graphics = FerretGraphics::new_without_depth_buffer(TestScreen::new());
+        graphics.draw_tilemap(&tilemap, (4, 0), 0);
+
+        // Camera x 4 scrolls the layer by 2
+        assert_eq!(graphics.screen.get_pixel(1, 0), (255, 0, 0));
+        assert_eq!(graphics.screen.get_pixel(2, 0), (0, 0, 0));
+        assert_eq!(graphics.screen.get_pixel(6, 0), (255, 0, 0));
+        assert_eq!(graphics.screen.get_pixel(10, 0), (0, 0, 0));
+
+        // Tile 0 animates into the colour-keyed tile 1
+        let mut graphics = FerretGraphics::new_without_depth_buffer(TestScreen::new());
+        graphics.draw_tilemap(&tilemap, (4, 0), 100);
+        assert_eq!(graphics.screen.get_pixel(1, 0), (0, 0, 0));
+
+        // Tiles wider than the texture draw nothing
+        let mut tileset = tileset;
+        tileset.tile_width = 16;
+        let tilemap = Tilemap { tileset, layers: &layers };
+
+        let mut graphics = FerretGraphics::new_without_depth_buffer(TestScreen::new());
+        graphics.draw_tilemap(&tilemap, (0, 0), 0);
+        assert!(graphics.screen.pixels.iter().all(|pixel| *pixel == (0, 0, 0)));
+    }
+
+    #[test]
+    fn draw_sprite_transformed_rotates_and_scales() {
+        // 2x1 texture: red, green
+        let data = [255, 0, 0, 0, 255, 0];
+        let texture = Texture::new(&data, 2, 1);
+        let source = Rect::new(0, 0, 2, 1);
+
+        // Identity matches a plain blit
+        let mut graphics = FerretGraphics::new_without_depth_buffer(TestScreen::new());
+        let identity = SpriteTransform::new(0.0, Vector2::new(1.0, 1.0), Vector2::new(0.0, 0.0));
+        graphics.draw_sprite_transformed(&texture, source, 3, 4, &identity, &SpriteOptions::new());
+        let expected = graphics.screen.pixels;
+
+        let mut graphics = FerretGraphics::new_without_depth_buffer(TestScreen::new());
+        graphics.draw_sprite(&texture, source, 3, 4, &SpriteOptions::new());
+        assert!(graphics.screen.pixels == expected);
+
+        // A quarter turn at twice the size stands the sprite up, 2x4 pixels
+        let transform = SpriteTransform::new(core::f32::consts::FRAC_PI_2, Vector2::new(2.0, 2.0), Vector2::new(0.0, 0.0));
+        let mut graphics = FerretGraphics::new_without_depth_buffer(TestScreen::new());
+        graphics.draw_sprite_transformed(&texture, source, 8, 8, &transform, &SpriteOptions::new());
+
+        assert_eq!(graphics.screen.get_pixel(7, 8), (255, 0, 0));
+        assert_eq!(graphics.screen.get_pixel(6, 9), (255, 0, 0));
+        assert_eq!(graphics.screen.get_pixel(7, 11), (0, 255, 0));
+        assert_eq!(graphics.screen.get_pixel(8, 8), (0, 0, 0));
+        assert_eq!(graphics.screen.pixels.iter().filter(|pixel| **pixel != (0, 0, 0)).count(), 8);
+    }
+
+    #[test]
+    fn draw_floor_fills_below_horizon() {
+        // 2x1 texture: red, green
+        let data = [255, 0, 0, 0, 255, 0];
+        let source = FloorSource::Texture(Texture::new(&data, 2, 1));
+
+        let camera = FloorCamera {
+            position: Vector2::new(0.0, 0.0),
+            height: 8.0,
+            yaw: 0.0,
+            horizon: 7,
+            scale: 16.0
+        };
+
+        let mut graphics = FerretGraphics::new_without_depth_buffer(TestScreen::new());
+        graphics.draw_floor(&source, &camera);
+
+        // Looking along +x, every row lies across a single texture column
+        for y in 0..HEIGHT {
+            let first = graphics.screen.get_pixel(0, y);
+            assert_eq!(first == (0, 0, 0), y <= 7);
+
+            for x in 0..WIDTH {
+                assert_eq!(graphics.screen.get_pixel(x, y), first);
+            }
+        }
+
+        // Row 15 is 128 / 7.5 = 17.1 units ahead, row 12 is 128 / 4.5 = 28.4
+        assert_eq!(graphics.screen.get_pixel(0, 15), (0, 255, 0));
+        assert_eq!(graphics.screen.get_pixel(0, 12), (255, 0, 0));
+    }
+
+    #[test]
+    fn floor_tilemap_samples_tiles() {
+        // 2x1 tileset of 1x1 tiles: red, green
+        let data = [255, 0, 0, 0, 255, 0];
+        let tiles = [Some(Tile::new(1)), None];
+        let mut tileset = Tileset {
+            texture: Texture::new(&data, 2, 1),
+            tile_width: 1,
+            tile_height: 1,
+            color_key: None,
+            animations: &[]
+        };
+
+        let source = FloorSource::Tilemap { tileset, layer: TilemapLayer::new(&tiles, 2, 1), time_millis: 0 };
+        assert_eq!(source.sample(0, 0), Some((0, 255, 0)));
+        assert_eq!(source.sample(1, 0), None);
+        assert_eq!(source.sample(-2, 3), Some((0, 255, 0)));
+
+        // Tiles wider than the texture sample nothing
+        tileset.tile_width = 4;
+        let source = FloorSource::Tilemap { tileset, layer: TilemapLayer::new(&tiles, 2, 1), time_millis: 0 };
+        assert_eq!(source.sample(0, 0), None);
+
+        let camera = FloorCamera {
+            position: Vector2::new(0.0, 0.0),
+            height: 8.0,
+            yaw: 0.0,
+            horizon: 7,
+            scale: 16.0
+        };
+
+        let mut graphics = FerretGraphics::new_without_depth_buffer(TestScreen::new());
+        graphics.draw_floor(&source, &camera);
+        assert!(graphics.screen.pixels.iter().all(|pixel| *pixel == (0, 0, 0)));
+    }
+
+    #[test]
+    fn draw_floor_skips_empty_sources_and_hidden_floors() {
+        let data = [255, 0, 0, 0, 255, 0];
+        let tiles = [Some(Tile::new(1))];
+        let tileset = Tileset {
+            texture: Texture::new(&data, 2, 1),
+            tile_width: 1,
+            tile_height: 1,
+            color_key: None,
+            animations: &[]
+        };
+
+        let mut camera = FloorCamera {
+            position: Vector2::new(0.0, 0.0),
+            height: 8.0,
+            yaw: 0.0,
+            horizon: 7,
+            scale: 16.0
+        };
+
+        let sources = [
+            FloorSource::Texture(Texture::new(&data, 0, 1)),
+            FloorSource::Texture(Texture::new(&data, 2, 0)),
+            FloorSource::Tilemap { tileset, layer: TilemapLayer::new(&tiles, 0, 1), time_millis: 0 },
+            FloorSource::Tilemap { tileset, layer: TilemapLayer::new(&tiles, 1, 0), time_millis: 0 },
+            FloorSource::Tilemap { tileset: Tileset { texture: Texture::new(&data, 2, 0), ..tileset }, layer: TilemapLayer::new(&tiles, 1, 1), time_millis: 0 }
+        ];
+
+        for source in &sources {
+            assert!(source.is_empty());
+            assert_eq!(source.sample(3, -5), None);
+
+            let mut graphics = FerretGraphics::new_without_depth_buffer(TestScreen::new());
+            graphics.draw_floor(source, &camera);
+            assert_eq!(lit_pixels(&graphics.screen), 0);
+        }
+
+        // A horizon at the last possible row leaves no floor to draw
+        let source = FloorSource::Tilemap { tileset, layer: TilemapLayer::new(&tiles, 1, 1), time_millis: 0 };
+        assert!(!source.is_empty());
+        camera.horizon = u16::MAX;
+
+        let mut graphics = FerretGraphics::new_without_depth_buffer(TestScreen::new());
+        graphics.draw_floor(&source, &camera);
+        assert_eq!(lit_pixels(&graphics.screen), 0);
+    }
+
+    #[test]
+    fn draw_text_in_aligns_and_clips() {
+        let white = Color(1.0, 1.0, 1.0);
+
+        let mut graphics = FerretGraphics::new_without_depth_buffer(TestScreen::new());
+        let style = TextStyle {
+            align: TextAlign::Right,
+            ..TextStyle::new(&TINY_FONT, white)
+        };
+
+        graphics.draw_text_in(Rect::new(2, 2, 10, 12), "%% %%", &style);
+
+        for y in 0..HEIGHT {
+            for x in 0..WIDTH {
+                let inside = (2..10).contains(&x) && (2..12).contains(&y);
+                assert!(inside || graphics.screen.get_pixel(x, y) == (0, 0, 0));
+            }
+        }
+
+        // Right aligned "%%" is 7 pixels wide, its first column starts at 3,
+        // the second line is partly cut off by the bottom of the rectangle
+        assert_eq!(graphics.screen.get_pixel(3, 2), (255, 255, 255));
+        assert_eq!(graphics.screen.get_pixel(3, 8), (255, 255, 255));
+        assert_eq!(graphics.screen.get_pixel(2, 2), (0, 0, 0));
+    }
+
+    #[test]
+    fn draw_string_maps_unicode() {
+        let white = Color(1.0, 1.0, 1.0);
+
+        let mut graphics = FerretGraphics::new_without_depth_buffer(TestScreen::new());
+        graphics.draw_string(0, 0, "ã😀", &GLCD, white, None);
+
+        // Tilde above the a, the emoji falls back to an empty box
+        assert_eq!(graphics.screen.get_pixel(1, 0), (255, 255, 255));
+        assert_eq!(graphics.screen.get_pixel(6, 0), (0, 0, 0));
+        assert_eq!(graphics.screen.get_pixel(6, 1), (255, 255, 255));
+        assert_eq!(graphics.screen.get_pixel(8, 3), (0, 0, 0));
+    }
+
+    #[test]
+    fn draw_string_continues_after_too_long_lines() {
+        let white = Color(1.0, 1.0, 1.0);
+
+        // The first line runs off the screen, the second still starts at x
+        let mut graphics = FerretGraphics::new_without_depth_buffer(TestScreen::new());
+        graphics.draw_string(0, 0, "%%%%%%\n%", &TINY_FONT, white, None);
+        let mut expected = FerretGraphics::new_without_depth_buffer(TestScreen::new());
+        expected.draw_string(0, 0, "%%%%%%", &TINY_FONT, white, None);
+        expected.draw_string(0, TINY_FONT.line_height as u16, "%", &TINY_FONT, white, None);
+        assert!(graphics.screen.pixels == expected.screen.pixels);
+        assert!(lit_pixels(&graphics.screen) > 0);
+
+        let chars = ['x', '%', '%', '%', '%', '%', '%', '\n', '%'];
+        let mut from_array = FerretGraphics::new_without_depth_buffer(TestScreen::new());
+        from_array.draw_char_array(0, 0, &chars, 1, &TINY_FONT, white, None);
+        assert!(from_array.screen.pixels == expected.screen.pixels);
+
+        // Lines far down and far right neither overflow nor draw
+        let mut graphics = FerretGraphics::new_without_depth_buffer(TestScreen::new());
+        graphics.draw_string(u16::MAX - 1, u16::MAX - 1, "%%\n\n%", &TINY_FONT, white, None);
+        graphics.draw_string(u16::MAX - 1, 0, "%%%", &TINY_FONT, white, None);
+        assert_eq!(lit_pixels(&graphics.screen), 0);
+    }
+
+    #[test]
+    fn draw_text_in_reads_rows_past_32() {
+        // One glyph, one column of 40 rows with rows 0 and 33 lit
+        let font = Font {
+            data: &[1, 0, 0, 0, 2],
+            cell_width: 1,
+            cell_height: 40,
+            widths: None,
+            spacing: 0,
+            baseline: 40,
+            line_height: 40,
+            glyphs: &[GlyphRange { first: 'a', last: 'a', glyph: 0 }],
+            fallback: None
+        };
+
+        let mut graphics = FerretGraphics::new_without_depth_buffer(TestScreen::new());
+        graphics.draw_text_in(Rect::new(0, -32, 16, 16), "a", &TextStyle::new(&font, Color(1.0, 1.0, 1.0)));
+
+        assert_eq!(graphics.screen.get_pixel(0, 1), (255, 255, 255));
+        assert_eq!(lit_pixels(&graphics.screen), 1);
+        assert_eq!(font.column(0, 0), 1);
+    }
+
+    #[test]
+    fn try_draw_mesh_rejects_out_of_range_index() {
+        let mut depth_buffer = [0.0f32; (WIDTH * HEIGHT) as usize];
+        let mut graphics = FerretGraphics::new(TestScreen::new(), &mut depth_buffer);
+
+        let result = graphics.try_draw_mesh(
+            &triangle_vertices(),
+            &[(0, 1, 2), (0, 1, 3)],
+            &PassThroughShader,
+            &PassThroughShader);
+
+        assert_eq!(result, Err(DrawMeshError::IndexOutOfRange { triangle: 1, index: 3 }));
+        assert!(graphics.screen.pixels.iter().all(|pixel| *pixel == (0, 0, 0)));
+    }
+
+    #[test]
+    fn try_draw_mesh_rejects_non_finite_vertex() {
+        let mut depth_buffer = [0.0f32; (WIDTH * HEIGHT) as usize];
+        let mut graphics = FerretGraphics::new(TestScreen::new(), &mut depth_buffer);
+
+        let mut vertices = triangle_vertices();
+        vertices[1][0] = f32::NAN;
+
+        let result = graphics.try_draw_mesh(&vertices, &[(0, 1, 2)], &PassThroughShader, &PassThroughShader);
+        assert_eq!(result, Err(DrawMeshError::NonFiniteVertex { index: 1 }));
+
+        vertices[1][0] = 1.0;
+        vertices[2][3] = 0.0;
+
+        let result = graphics.try_draw_mesh(&vertices, &[(0, 1, 2)], &PassThroughShader, &PassThroughShader);
+        assert_eq!(result, Err(DrawMeshError::NonFiniteVertex { index: 2 }));
+    }
+
+    #[test]
+    fn try_draw_mesh_draws_valid_mesh() {
+        let mut depth_buffer = [0.0f32; (WIDTH * HEIGHT) as usize];
+        let mut graphics = FerretGraphics::new(TestScreen::new(), &mut depth_buffer);
+
+        let result = graphics.try_draw_mesh(
+            &triangle_vertices(),
+            &[(0, 1, 2)],
+            &PassThroughShader,
+            &PassThroughShader);
+
+        assert_eq!(result, Ok(()));
+        assert!(graphics.screen.pixels.contains(&(255, 255, 255)));
+    }
+}
@@ -0,0 +1,241 @@
+use crate::BLOCK_SIZE;
+use crate::FerretGraphics;
+use crate::ferret_3d::parallel_queue::ParallelQueue;
+use crate::ferret_3d::parallel_queue::QueuedRenderState;
+use crate::ferret_3d::pixel_shader_trait::PixelShaderTrait;
+use crate::ferret_3d::render_scale::RenderScale;
+use crate::ferret_3d::vertex_shader_trait::VertexShaderTrait;
+use crate::interlace_field::InterlaceField;
+use crate::screen_trait::ScreenTrait;
+
+use nalgebra::SVector;
+
+use std::thread;
+
+/// One band of rows of the target, drawn by a worker thread.
+struct BandScreen<'b> {
+    width: u16,
+    height: u16,
+    first_row: u16,
+    pixels: &'b mut [u8],
+    bytes_per_pixel: usize,
+    field: Option<InterlaceField>
+}
+
+impl<'b> BandScreen<'b> {
+    fn index(&self, x: u16, y: u16) -> usize {
+        ((y - self.first_row) as usize * self.width as usize + x as usize) * self.bytes_per_pixel
+    }
+}
+
+impl<'b> ScreenTrait for BandScreen<'b> {
+    fn get_width(&self) -> u16 {
+        self.width
+    }
+
+    fn get_height(&self) -> u16 {
+        self.height
+    }
+
+    fn set_pixel(&mut self, x: u16, y: u16, r: u8, g: u8, b: u8) {
+        let index = self.index(x, y);
+        self.pixels[index] = r;
+        self.pixels[index + 1] = g;
+        self.pixels[index + 2] = b;
+    }
+
+    fn get_pixel(&self, x: u16, y: u16) -> (u8, u8, u8) {
+        let index = self.index(x, y);
+        (self.pixels[index], self.pixels[index + 1], self.pixels[index + 2])
+    }
+
+    fn clear(&mut self, r: u8, g: u8, b: u8) {
+        for pixel in self.pixels.chunks_exact_mut(self.bytes_per_pixel) {
+            pixel[..3].copy_from_slice(&[r, g, b]);
+        }
+    }
+
+    fn update_screen(&mut self) -> Result<(), ()> {
+        Ok(())
+    }
+
+    fn get_interlace_field(&self) -> Option<InterlaceField> {
+        self.field
+    }
+}
+
+fn split_bands<T>(buffer: Option<&mut [T]>, band_size: usize, bands: usize) -> Vec<Option<&mut [T]>> {
+    match buffer {
+        Some(buffer) => buffer.chunks_mut(band_size).map(Some).chain(std::iter::repeat_with(|| None)).take(bands).collect(),
+        None => (0..bands).map(|_| None).collect()
+    }
+}
+
+impl<'a, TScreen> FerretGraphics<'a, TScreen>
+where
+    TScreen: ScreenTrait
+{
+    /// Same as `draw_mesh`, but triangles are queued in `parallel_queue`
+    /// together with the current render state. Call `flush_parallel` once
+    /// all meshes of the frame are queued.
+    pub fn queue_mesh_parallel
+        <'s,
+        TVertexShader: VertexShaderTrait<TVECTOR_IN_SIZE, TVECTOR_OUT_SIZE> + ?Sized,
+        TPixelShader: PixelShaderTrait<TVECTOR_OUT_SIZE> + Sync,
+        const TVECTOR_IN_SIZE: usize,
+        const TVECTOR_OUT_SIZE: usize>(
+            &mut self,
+            parallel_queue: &mut ParallelQueue<'s, TVECTOR_OUT_SIZE>,
+            vertices: &[SVector<f32, TVECTOR_IN_SIZE>],
+            indexes: &[(usize, usize, usize)],
+            vertex_shader: &TVertexShader,
+            pixel_shader: &'s TPixelShader
+        ) {
+            parallel_queue.insert_mesh(self.queued_render_state(), pixel_shader);
+
+            for i in indexes {
+                let v0 = vertex_shader.process(&vertices[i.0]);
+                let v1 = vertex_shader.process(&vertices[i.1]);
+                let v2 = vertex_shader.process(&vertices[i.2]);
+
+                if !Self::is_vertex_finite(&v0)
+                    || !Self::is_vertex_finite(&v1)
+                    || !Self::is_vertex_finite(&v2)
+                    || self.does_triangle_clip_completely(v0, v1, v2) {
+                    continue;
+                }
+
+                let (v0, v1, v2) = self.transform_triangle(v0, v1, v2);
+                parallel_queue.insert_triangle(v0, v1, v2);
+            }
+    }
+
+    /// Draws every mesh queued in `parallel_queue` and empties it. The
+    /// target is split into horizontal bands rasterized on up to `threads`
+    /// threads, each writing its own rows of the target, depth, coarse depth
+    /// and ID buffers. Bands draw the meshes in submission order, so the
+    /// output matches `draw_mesh`. Screens without a frame buffer are drawn
+    /// on the calling thread.
+    pub fn flush_parallel<const TVECTOR_SIZE: usize>(
+        &mut self,
+        parallel_queue: &mut ParallelQueue<'_, TVECTOR_SIZE>,
+        threads: usize
+    ) {
+        let state = self.queued_render_state();
+        let width = self.target_width() as usize;
+        let height = self.target_height() as usize;
+
+        // Bands are whole blocks high, so blocks never straddle two threads
+        let band_rows = height.div_ceil(threads.max(1)).div_ceil(BLOCK_SIZE) * BLOCK_SIZE;
+        let bands = height.div_ceil(band_rows.max(1));
+
+        let field = if self.interlaced_render && self.scale_factor() == 1 {
+            self.screen.get_interlace_field()
+        } else {
+            None
+        };
+
+        let target = match &mut self.scene_buffer {
+            Some(scene_buffer) if self.render_scale != RenderScale::Full => Some((&mut scene_buffer[..], 3)),
+            _ => self.screen.get_frame_buffer().map(|frame_buffer| (frame_buffer.pixels, frame_buffer.bytes_per_pixel))
+        };
+
+        match target {
+            Some((pixels, bytes_per_pixel)) if bands > 1 && pixels.len() >= width * height * bytes_per_pixel => {
+                let blocks_x = width.div_ceil(BLOCK_SIZE);
+                let pixel_bands = split_bands(Some(pixels), band_rows * width * bytes_per_pixel, bands);
+                let depth_bands = split_bands(self.depth_buffer.as_deref_mut(), band_rows * width, bands);
+                let coarse_bands = split_bands(self.coarse_depth_buffer.as_deref_mut(), band_rows / BLOCK_SIZE * blocks_x * 2, bands);
+                let id_bands = split_bands(self.id_buffer.as_deref_mut(), band_rows * width, bands);
+                let parallel_queue = &*parallel_queue;
+
+                thread::scope(|scope| {
+                    let buffers = pixel_bands.into_iter()
+                        .zip(depth_bands)
+                        .zip(coarse_bands.into_iter().zip(id_bands));
+
+                    for (band, ((pixels, depth_buffer), (coarse_depth_buffer, id_buffer))) in buffers.enumerate() {
+                        scope.spawn(move || {
+                            let rows = (band * band_rows, ((band + 1) * band_rows).min(height));
+                            let screen = BandScreen {
+                                width: width as u16,
+                                height: height as u16,
+                                first_row: rows.0 as u16,
+                                pixels: pixels.unwrap(),
+                                bytes_per_pixel,
+                                field
+                            };
+
+                            let mut graphics = FerretGraphics::new_without_depth_buffer(screen);
+                            graphics.depth_buffer = depth_buffer;
+                            graphics.coarse_depth_buffer = coarse_depth_buffer;
+                            graphics.id_buffer = id_buffer;
+                            graphics.interlaced_render = field.is_some();
+                            graphics.rows = rows;
+
+                            graphics.draw_parallel_queue(parallel_queue);
+                        });
+                    }
+                });
+            },
+            _ => self.draw_parallel_queue(parallel_queue)
+        }
+
+        self.apply_queued_render_state(state);
+        parallel_queue.clear();
+    }
+
+    /// Same as `draw_mesh`, but rasterized on up to `threads` threads like
+    /// `flush_parallel`. Queue the meshes of a frame and flush them once
+    /// instead of calling this for each of them.
+    pub fn draw_mesh_parallel
+        <TVertexShader: VertexShaderTrait<TVECTOR_IN_SIZE, TVECTOR_OUT_SIZE> + ?Sized,
+        TPixelShader: PixelShaderTrait<TVECTOR_OUT_SIZE> + Sync,
+        const TVECTOR_IN_SIZE: usize,
+        const TVECTOR_OUT_SIZE: usize>(
+            &mut self,
+            vertices: &[SVector<f32, TVECTOR_IN_SIZE>],
+            indexes: &[(usize, usize, usize)],
+            vertex_shader: &TVertexShader,
+            pixel_shader: &TPixelShader,
+            threads: usize
+        ) {
+            let mut parallel_queue = ParallelQueue::new();
+            self.queue_mesh_parallel(&mut parallel_queue, vertices, indexes, vertex_shader, pixel_shader);
+            self.flush_parallel(&mut parallel_queue, threads);
+    }
+
+    fn draw_parallel_queue<const TVECTOR_SIZE: usize>(&mut self, parallel_queue: &ParallelQueue<'_, TVECTOR_SIZE>) {
+        for mesh in parallel_queue.meshes() {
+            self.apply_queued_render_state(mesh.state);
+
+            for (v0, v1, v2) in mesh.triangles {
+                self.draw_triangle(mesh.pixel_shader, v0, v1, v2);
+            }
+        }
+    }
+
+    fn queued_render_state(&self) -> QueuedRenderState {
+        QueuedRenderState {
+            cull_mode: self.cull_mode,
+            depth_test: self.depth_test,
+            depth_write: self.depth_write,
+            depth_bias_constant: self.depth_bias_constant,
+            depth_bias_slope_scale: self.depth_bias_slope_scale,
+            blend_mode: self.blend_mode,
+            rasterizer: self.rasterizer,
+            object_id: self.object_id
+        }
+    }
+
+    fn apply_queued_render_state(&mut self, state: QueuedRenderState) {
+        self.cull_mode = state.cull_mode;
+        self.depth_test = state.depth_test;
+        self.depth_write = state.depth_write;
+        self.depth_bias_constant = state.depth_bias_constant;
+        self.depth_bias_slope_scale = state.depth_bias_slope_scale;
+        self.blend_mode = state.blend_mode;
+        self.rasterizer = state.rasterizer;
+        self.object_id = state.object_id;
+    }
+}
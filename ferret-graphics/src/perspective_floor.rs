@@ -0,0 +1,83 @@
+use crate::ferret_3d::texture::Texture;
+use crate::tilemap::TilemapLayer;
+use crate::tilemap::Tileset;
+
+use nalgebra::Vector2;
+
+/// Viewpoint of `draw_floor`. World units are texture pixels.
+#[derive(Copy, Clone)]
+pub struct FloorCamera {
+    /// Position over the floor plane.
+    pub position: Vector2<f32>,
+    pub height: f32,
+
+    /// Viewing direction, 0.0 looks along +x.
+    pub yaw: f32,
+
+    /// Screen row of the horizon, the floor covers the rows below it.
+    pub horizon: u16,
+
+    /// Distance from the eye to the screen in pixels, larger values zoom in.
+    pub scale: f32
+}
+
+/// What the floor plane shows. Both repeat forever in every direction.
+#[derive(Copy, Clone)]
+pub enum FloorSource<'t> {
+    Texture(Texture<'t>),
+
+    Tilemap {
+        tileset: Tileset<'t>,
+        layer: TilemapLayer<'t>,
+        time_millis: u32
+    }
+}
+
+impl<'t> FloorSource<'t> {
+    /// True when there is nothing to repeat, such as a texture or layer
+    /// without pixels or tiles, or a texture narrower than one tile.
+    pub fn is_empty(&self) -> bool {
+        match self {
+            FloorSource::Texture(texture) => texture.width == 0 || texture.height == 0,
+            FloorSource::Tilemap { tileset, layer, .. } => {
+                tileset.tile_width == 0 || tileset.tile_height == 0
+                    || tileset.texture.width < tileset.tile_width || tileset.texture.height == 0
+                    || layer.width == 0 || layer.height == 0
+            }
+        }
+    }
+
+    /// Colour at world position (x, y), None for empty tiles and sources.
+    pub fn sample(&self, x: i32, y: i32) -> Option<(u8, u8, u8)> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let (texture, x, y) = match self {
+            FloorSource::Texture(texture) => (texture, x, y),
+            FloorSource::Tilemap { tileset, layer, time_millis } => {
+                let tile_width = tileset.tile_width as i32;
+                let tile_height = tileset.tile_height as i32;
+
+                // Whole maps can be wider than an i32
+                let x = (x as i64).rem_euclid(tile_width as i64 * layer.width as i64) as i32;
+                let y = (y as i64).rem_euclid(tile_height as i64 * layer.height as i64) as i32;
+
+                let tile = layer.get(x / tile_width, y / tile_height)?;
+                let index = tileset.animated_index(tile.index, *time_millis) as i32;
+                let columns = tileset.texture.width as i32 / tile_width;
+
+                let tx = if tile.flip_x { tile_width - 1 - x % tile_width } else { x % tile_width };
+                let ty = if tile.flip_y { tile_height - 1 - y % tile_height } else { y % tile_height };
+
+                (&tileset.texture, index % columns * tile_width + tx, index / columns * tile_height + ty)
+            }
+        };
+
+        let x = x.rem_euclid(texture.width as i32) as usize;
+        let y = y.rem_euclid(texture.height as i32) as usize;
+        let index = (y * texture.width as usize + x) * 3;
+
+        Some((texture.data[index], texture.data[index + 1], texture.data[index + 2]))
+    }
+}
@@ -0,0 +1,7 @@
+pub mod color_grading;
+pub mod depth_buffer_view;
+pub mod depth_outline;
+pub mod fade;
+pub mod mosaic;
+pub mod post_process_trait;
+pub mod scanlines;
\ No newline at end of file
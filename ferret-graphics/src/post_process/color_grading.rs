@@ -0,0 +1,45 @@
+use crate::screen_trait::ScreenTrait;
+use crate::post_process::depth_buffer_view::DepthBufferView;
+use crate::post_process::post_process_trait::PostProcessTrait;
+
+/// Remaps colours through a 3D lookup table of `size`^3 RGB888 entries,
+/// laid out with red varying fastest, then green, then blue.
+pub struct ColorGrading<'a> {
+    pub lut: &'a [u8],
+    pub size: usize
+}
+
+impl<'a> ColorGrading<'a> {
+    pub fn new(lut: &'a [u8], size: usize) -> Self {
+        Self {
+            lut,
+            size
+        }
+    }
+
+    fn lut_coordinate(&self, value: u8) -> usize {
+        (value as usize * (self.size - 1) + 127) / 255
+    }
+}
+
+impl<'a> PostProcessTrait for ColorGrading<'a> {
+    fn process<TScreen: ScreenTrait>(&self, screen: &mut TScreen, _depth_buffer: Option<&DepthBufferView>) {
+        if self.size < 2 || self.lut.len() < self.size * self.size * self.size * 3 {
+            return;
+        }
+
+        for y in 0..screen.get_height() {
+            for x in 0..screen.get_width() {
+                let (r, g, b) = screen.get_pixel(x, y);
+
+                let r = self.lut_coordinate(r);
+                let g = self.lut_coordinate(g);
+                let b = self.lut_coordinate(b);
+
+                let index = ((b * self.size + g) * self.size + r) * 3;
+
+                screen.set_pixel(x, y, self.lut[index], self.lut[index + 1], self.lut[index + 2]);
+            }
+        }
+    }
+}
@@ -0,0 +1,28 @@
+/// Read-only view of the depth buffer handed to post-process passes. It is
+/// addressed in screen coordinates, even when the scene is rendered at a
+/// reduced scale; screen edges beyond the scaled buffer read its last row
+/// and column.
+pub struct DepthBufferView<'a> {
+    data: &'a [f32],
+    width: u16,
+    height: u16,
+    scale: u16
+}
+
+impl<'a> DepthBufferView<'a> {
+    pub fn new(data: &'a [f32], width: u16, height: u16, scale: u16) -> Self {
+        Self {
+            data,
+            width,
+            height,
+            scale
+        }
+    }
+
+    pub fn get(&self, x: u16, y: u16) -> f32 {
+        let x = (x / self.scale).min(self.width.saturating_sub(1));
+        let y = (y / self.scale).min(self.height.saturating_sub(1));
+        let index = y as usize * self.width as usize + x as usize;
+        self.data[index]
+    }
+}
@@ -0,0 +1,46 @@
+use crate::color::Color;
+use crate::screen_trait::ScreenTrait;
+use crate::post_process::depth_buffer_view::DepthBufferView;
+use crate::post_process::post_process_trait::PostProcessTrait;
+
+/// Draws `color` over pixels whose depth differs from the right or bottom
+/// neighbour by more than `threshold`. Does nothing without a depth buffer.
+pub struct DepthOutline {
+    pub threshold: f32,
+    pub color: Color
+}
+
+impl DepthOutline {
+    pub fn new(threshold: f32, color: Color) -> Self {
+        Self {
+            threshold,
+            color
+        }
+    }
+}
+
+impl PostProcessTrait for DepthOutline {
+    fn process<TScreen: ScreenTrait>(&self, screen: &mut TScreen, depth_buffer: Option<&DepthBufferView>) {
+        let depth_buffer = match depth_buffer {
+            Some(depth_buffer) => depth_buffer,
+            None => return
+        };
+
+        let color = self.color.as_rgb888();
+        let width = screen.get_width();
+        let height = screen.get_height();
+
+        for y in 0..height {
+            for x in 0..width {
+                let depth = depth_buffer.get(x, y);
+
+                let right = if x + 1 < width { depth_buffer.get(x + 1, y) } else { depth };
+                let bottom = if y + 1 < height { depth_buffer.get(x, y + 1) } else { depth };
+
+                if (depth - right).abs() > self.threshold || (depth - bottom).abs() > self.threshold {
+                    screen.set_pixel(x, y, color.0, color.1, color.2);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use crate::color::Color;
+use crate::screen_trait::ScreenTrait;
+use crate::post_process::depth_buffer_view::DepthBufferView;
+use crate::post_process::post_process_trait::PostProcessTrait;
+
+/// Blends the whole screen towards `color`. An `amount` of 0.0 leaves the
+/// frame untouched and 1.0 fills it, which covers both flashes and fades.
+pub struct Fade {
+    pub color: Color,
+    pub amount: f32
+}
+
+impl Fade {
+    pub fn new(color: Color, amount: f32) -> Self {
+        Self {
+            color,
+            amount
+        }
+    }
+}
+
+impl PostProcessTrait for Fade {
+    fn process<TScreen: ScreenTrait>(&self, screen: &mut TScreen, _depth_buffer: Option<&DepthBufferView>) {
+        let amount = self.amount.clamp(0.0, 1.0);
+
+        if amount <= 0.0 {
+            return;
+        }
+
+        let target = self.color.as_rgb888();
+        let target = (target.0 as f32, target.1 as f32, target.2 as f32);
+
+        for y in 0..screen.get_height() {
+            for x in 0..screen.get_width() {
+                let (r, g, b) = screen.get_pixel(x, y);
+
+                let r = r as f32 + (target.0 - r as f32) * amount;
+                let g = g as f32 + (target.1 - g as f32) * amount;
+                let b = b as f32 + (target.2 - b as f32) * amount;
+
+                screen.set_pixel(x, y, r as u8, g as u8, b as u8);
+            }
+        }
+    }
+}
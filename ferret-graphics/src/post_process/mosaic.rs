@@ -0,0 +1,43 @@
+use crate::screen_trait::ScreenTrait;
+use crate::post_process::depth_buffer_view::DepthBufferView;
+use crate::post_process::post_process_trait::PostProcessTrait;
+
+/// Pixelates the screen by filling each `block_size` square with the colour
+/// of its top-left pixel.
+pub struct Mosaic {
+    pub block_size: u16
+}
+
+impl Mosaic {
+    pub fn new(block_size: u16) -> Self {
+        Self {
+            block_size
+        }
+    }
+}
+
+impl PostProcessTrait for Mosaic {
+    fn process<TScreen: ScreenTrait>(&self, screen: &mut TScreen, _depth_buffer: Option<&DepthBufferView>) {
+        if self.block_size <= 1 {
+            return;
+        }
+
+        let width = screen.get_width();
+        let height = screen.get_height();
+
+        for block_y in (0..height).step_by(self.block_size as usize) {
+            for block_x in (0..width).step_by(self.block_size as usize) {
+                let (r, g, b) = screen.get_pixel(block_x, block_y);
+
+                let end_x = (block_x + self.block_size).min(width);
+                let end_y = (block_y + self.block_size).min(height);
+
+                for y in block_y..end_y {
+                    for x in block_x..end_x {
+                        screen.set_pixel(x, y, r, g, b);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,6 @@
+use crate::screen_trait::ScreenTrait;
+use crate::post_process::depth_buffer_view::DepthBufferView;
+
+pub trait PostProcessTrait {
+    fn process<TScreen: ScreenTrait>(&self, screen: &mut TScreen, depth_buffer: Option<&DepthBufferView>);
+}
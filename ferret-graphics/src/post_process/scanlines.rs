@@ -0,0 +1,58 @@
+use crate::screen_trait::ScreenTrait;
+use crate::post_process::depth_buffer_view::DepthBufferView;
+use crate::post_process::post_process_trait::PostProcessTrait;
+
+/// Darkens every `period`-th row and, with `aperture_mask`, tints columns
+/// towards red, green and blue like a CRT aperture grille.
+pub struct Scanlines {
+    pub darkness: f32,
+    pub period: u16,
+    pub aperture_mask: bool
+}
+
+impl Scanlines {
+    pub fn new(darkness: f32, period: u16) -> Self {
+        Self {
+            darkness,
+            period,
+            aperture_mask: false
+        }
+    }
+}
+
+impl PostProcessTrait for Scanlines {
+    fn process<TScreen: ScreenTrait>(&self, screen: &mut TScreen, _depth_buffer: Option<&DepthBufferView>) {
+        let period = self.period.max(1);
+        let line_factor = 1.0 - self.darkness.clamp(0.0, 1.0);
+        let mask_factor = 1.0 - self.darkness.clamp(0.0, 1.0) * 0.5;
+
+        for y in 0..screen.get_height() {
+            let is_scanline = y % period == period - 1;
+
+            if !is_scanline && !self.aperture_mask {
+                continue;
+            }
+
+            for x in 0..screen.get_width() {
+                let (r, g, b) = screen.get_pixel(x, y);
+                let mut color = (r as f32, g as f32, b as f32);
+
+                if self.aperture_mask {
+                    match x % 3 {
+                        0 => { color.1 *= mask_factor; color.2 *= mask_factor; },
+                        1 => { color.0 *= mask_factor; color.2 *= mask_factor; },
+                        _ => { color.0 *= mask_factor; color.1 *= mask_factor; }
+                    }
+                }
+
+                if is_scanline {
+                    color.0 *= line_factor;
+                    color.1 *= line_factor;
+                    color.2 *= line_factor;
+                }
+
+                screen.set_pixel(x, y, color.0 as u8, color.1 as u8, color.2 as u8);
+            }
+        }
+    }
+}
@@ -1,3 +1,4 @@
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub struct Rect<T> {
     pub left: T,
     pub top: T,
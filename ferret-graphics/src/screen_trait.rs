@@ -1,9 +1,31 @@
+use crate::interlace_field::InterlaceField;
+
+/// Pixels of a screen kept in memory, row by row. Each pixel takes
+/// `bytes_per_pixel` bytes, starting with red, green and blue.
+pub struct FrameBuffer<'b> {
+    pub pixels: &'b mut [u8],
+    pub bytes_per_pixel: usize
+}
+
 pub trait ScreenTrait {
     fn get_width(&self) -> u16;
     fn get_height(&self) -> u16;
 
     fn set_pixel(&mut self, x: u16, y: u16, r: u8, g: u8, b: u8);
+    fn get_pixel(&self, x: u16, y: u16) -> (u8, u8, u8);
     fn clear(&mut self, r: u8, g: u8, b: u8);
 
     fn update_screen(&mut self) -> Result<(), ()>;
+
+    /// Field of rows the next `update_screen` will send, for screens that
+    /// only refresh every other row per frame.
+    fn get_interlace_field(&self) -> Option<InterlaceField> {
+        None
+    }
+
+    /// Frame buffer of screens that keep one in memory. Parallel rendering
+    /// writes each band of rows into it directly.
+    fn get_frame_buffer(&mut self) -> Option<FrameBuffer<'_>> {
+        None
+    }
 }
\ No newline at end of file
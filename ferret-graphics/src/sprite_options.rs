@@ -0,0 +1,35 @@
+/// Texture colour and the colour to draw instead.
+pub type PaletteSwap = ((u8, u8, u8), (u8, u8, u8));
+
+/// How `draw_sprite` copies texture pixels to the screen.
+#[derive(Copy, Clone)]
+pub struct SpriteOptions<'p> {
+    /// Texture colour left out of the copy.
+    pub color_key: Option<(u8, u8, u8)>,
+
+    /// Opacity of the sprite, 255 is fully opaque.
+    pub alpha: u8,
+
+    pub flip_x: bool,
+    pub flip_y: bool,
+
+    pub palette: Option<&'p [PaletteSwap]>
+}
+
+impl<'p> SpriteOptions<'p> {
+    pub fn new() -> Self {
+        Self {
+            color_key: None,
+            alpha: 255,
+            flip_x: false,
+            flip_y: false,
+            palette: None
+        }
+    }
+}
+
+impl<'p> Default for SpriteOptions<'p> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,70 @@
+use crate::Rect;
+use crate::ferret_3d::texture::Texture;
+
+/// Region of a sprite sheet and the point of it placed at the draw position.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct SpriteFrame {
+    pub rect: Rect<i32>,
+    pub pivot: (i32, i32)
+}
+
+#[derive(Copy, Clone)]
+pub enum SpriteFrames<'f> {
+    /// Cells of equal size, numbered left to right then top to bottom.
+    Grid {
+        cell_width: u16,
+        cell_height: u16,
+        pivot: (i32, i32)
+    },
+
+    Rects(&'f [SpriteFrame])
+}
+
+/// Texture holding several sprites.
+#[derive(Copy, Clone)]
+pub struct SpriteSheet<'t> {
+    pub texture: Texture<'t>,
+    pub frames: SpriteFrames<'t>
+}
+
+impl<'t> SpriteSheet<'t> {
+    pub fn new(texture: Texture<'t>, frames: SpriteFrames<'t>) -> Self {
+        Self {
+            texture,
+            frames
+        }
+    }
+
+    pub fn frame_count(&self) -> usize {
+        match self.frames {
+            SpriteFrames::Grid { cell_width, cell_height, .. } => {
+                if cell_width == 0 || cell_height == 0 {
+                    return 0;
+                }
+
+                (self.texture.width / cell_width) as usize * (self.texture.height / cell_height) as usize
+            },
+            SpriteFrames::Rects(frames) => frames.len()
+        }
+    }
+
+    pub fn frame(&self, index: usize) -> Option<SpriteFrame> {
+        if index >= self.frame_count() {
+            return None;
+        }
+
+        match self.frames {
+            SpriteFrames::Grid { cell_width, cell_height, pivot } => {
+                let columns = (self.texture.width / cell_width) as usize;
+                let left = (index % columns) as i32 * cell_width as i32;
+                let top = (index / columns) as i32 * cell_height as i32;
+
+                Some(SpriteFrame {
+                    rect: Rect::new(left, top, left + cell_width as i32, top + cell_height as i32),
+                    pivot
+                })
+            },
+            SpriteFrames::Rects(frames) => Some(frames[index])
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use nalgebra::Matrix2;
+use nalgebra::Rotation2;
+use nalgebra::Vector2;
+
+/// Rotation and scale applied by `draw_sprite_transformed` around `pivot`,
+/// given in pixels from the top left of the sprite.
+#[derive(Copy, Clone)]
+pub struct SpriteTransform {
+    pub rotation: f32,
+    pub scale: Vector2<f32>,
+    pub pivot: Vector2<f32>
+}
+
+impl SpriteTransform {
+    pub fn new(rotation: f32, scale: Vector2<f32>, pivot: Vector2<f32>) -> Self {
+        Self {
+            rotation,
+            scale,
+            pivot
+        }
+    }
+
+    /// Maps sprite offsets from the pivot to screen offsets.
+    pub fn matrix(&self) -> Matrix2<f32> {
+        Rotation2::new(self.rotation).matrix() * Matrix2::new(
+            self.scale.x, 0.0,
+            0.0, self.scale.y)
+    }
+}
@@ -0,0 +1,153 @@
+use crate::color::Color;
+use crate::fonts::font::Font;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum TextAlign {
+    Left,
+    Center,
+    Right
+}
+
+/// What `draw_text_in` does with lines wider than the rectangle.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum TextWrap {
+    /// Lines only break at '\n', the rest is clipped.
+    None,
+
+    /// Lines break between words, or inside words longer than a line.
+    Word,
+
+    /// Lines only break at '\n' and end in "..." when cut.
+    Ellipsis
+}
+
+/// How `draw_text_in` lays out and colours text.
+#[derive(Copy, Clone)]
+pub struct TextStyle<'f> {
+    pub font: &'f Font<'f>,
+    pub align: TextAlign,
+    pub wrap: TextWrap,
+    pub font_color: Color,
+
+    /// Colour of the unlit pixels of each glyph, None leaves them untouched.
+    pub bg_color: Option<Color>
+}
+
+impl<'f> TextStyle<'f> {
+    /// Left aligned text breaking between words, with no background.
+    pub fn new(font: &'f Font<'f>, font_color: Color) -> Self {
+        Self {
+            font,
+            align: TextAlign::Left,
+            wrap: TextWrap::Word,
+            font_color,
+            bg_color: None
+        }
+    }
+}
+
+/// Width in pixels of a single line, without the spacing after its last
+/// character.
+pub fn line_width(line: &str, font: &Font) -> u16 {
+    let width: u16 = line.chars().map(|c| font.advance(c)).sum();
+    width.saturating_sub(font.spacing as u16)
+}
+
+/// Size of the block of lines `text` is split into by '\n'.
+pub fn measure_text(text: &str, font: &Font) -> (u16, u16) {
+    let mut width = 0;
+    let mut lines = 0;
+
+    for line in text.split('\n') {
+        width = width.max(line_width(line, font));
+        lines += 1;
+    }
+
+    (width, lines * font.line_height as u16)
+}
+
+/// Splits the first line to draw off `text`, returning the line and the text
+/// after it, or None after the last line. Word wrapping drops the space a
+/// line breaks at.
+pub fn next_line<'t>(text: &'t str, font: &Font, max_width: u16, wrap: TextWrap) -> (&'t str, Option<&'t str>) {
+    let end = text.find('\n').unwrap_or(text.len());
+    let rest = text.get(end + 1..);
+
+    if wrap != TextWrap::Word || line_width(&text[..end], font) <= max_width {
+        return (&text[..end], rest);
+    }
+
+    let mut width = 0;
+    let mut last_space = None;
+
+    for (index, c) in text[..end].char_indices() {
+        if c == ' ' {
+            last_space = Some(index);
+        }
+
+        width += font.advance(c);
+
+        if width - font.spacing as u16 > max_width {
+            return match last_space {
+                Some(space) if space > 0 => (&text[..space], Some(&text[space + 1..])),
+                // A single word wider than the line, always keep one character
+                _ => {
+                    let split = if index == 0 { c.len_utf8() } else { index };
+                    (&text[..split], Some(&text[split..]))
+                }
+            };
+        }
+    }
+
+    (&text[..end], rest)
+}
+
+/// Longest start of `line` that fits `max_width` once "..." is appended.
+pub fn truncate_with_ellipsis<'t>(line: &'t str, font: &Font, max_width: u16) -> &'t str {
+    let ellipsis = font.advance('.') * 3;
+    let mut width = 0;
+
+    for (index, c) in line.char_indices() {
+        width += font.advance(c);
+
+        if width + ellipsis - font.spacing as u16 > max_width {
+            return &line[..index];
+        }
+    }
+
+    line
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fonts::glcd::GLCD;
+
+    #[test]
+    fn measures_widest_line() {
+        assert_eq!(measure_text("ab\nabcd", &GLCD), (23, 18));
+        assert_eq!(measure_text("", &GLCD), (0, 9));
+    }
+
+    #[test]
+    fn word_wrap_breaks_between_words() {
+        // Three characters fit in 17 pixels
+        let (line, rest) = next_line("ab cd efgh", &GLCD, 17, TextWrap::Word);
+        assert_eq!((line, rest), ("ab", Some("cd efgh")));
+
+        let (line, rest) = next_line("cd efgh", &GLCD, 17, TextWrap::Word);
+        assert_eq!((line, rest), ("cd", Some("efgh")));
+
+        let (line, rest) = next_line("efgh", &GLCD, 17, TextWrap::Word);
+        assert_eq!((line, rest), ("efg", Some("h")));
+
+        assert_eq!(next_line("h", &GLCD, 17, TextWrap::Word), ("h", None));
+        assert_eq!(next_line("efgh\ni", &GLCD, 17, TextWrap::None), ("efgh", Some("i")));
+    }
+
+    #[test]
+    fn ellipsis_leaves_room_for_dots() {
+        // "..." takes 18 pixels, leaving room for two characters in 30
+        assert_eq!(truncate_with_ellipsis("abcdef", &GLCD, 30), "ab");
+    }
+}
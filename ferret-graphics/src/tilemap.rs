@@ -0,0 +1,89 @@
+use crate::ferret_3d::texture::Texture;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Tile {
+    /// Index of the tile in the tileset, left to right then top to bottom.
+    pub index: u16,
+    pub flip_x: bool,
+    pub flip_y: bool
+}
+
+impl Tile {
+    pub fn new(index: u16) -> Self {
+        Self {
+            index,
+            flip_x: false,
+            flip_y: false
+        }
+    }
+}
+
+/// Tile `first` cycles through the `count` tiles starting at it.
+#[derive(Copy, Clone)]
+pub struct TileAnimation {
+    pub first: u16,
+    pub count: u16,
+    pub frame_millis: u32
+}
+
+#[derive(Copy, Clone)]
+pub struct Tileset<'t> {
+    pub texture: Texture<'t>,
+    pub tile_width: u16,
+    pub tile_height: u16,
+
+    /// Texture colour left out when drawing tiles, so lower layers show.
+    pub color_key: Option<(u8, u8, u8)>,
+    pub animations: &'t [TileAnimation]
+}
+
+impl<'t> Tileset<'t> {
+    /// Tile to draw in place of `index` at `time_millis`.
+    pub fn animated_index(&self, index: u16, time_millis: u32) -> u16 {
+        match self.animations.iter().find(|animation| animation.first == index) {
+            Some(animation) if animation.count > 0 => {
+                let frame = time_millis / animation.frame_millis.max(1) % animation.count as u32;
+                index + frame as u16
+            },
+            _ => index
+        }
+    }
+}
+
+/// Grid of `width` x `height` tiles, row by row. The layer scrolls by the
+/// camera position times `parallax`, plus `offset`.
+#[derive(Copy, Clone)]
+pub struct TilemapLayer<'t> {
+    pub tiles: &'t [Option<Tile>],
+    pub width: u16,
+    pub height: u16,
+    pub parallax: (f32, f32),
+    pub offset: (i32, i32)
+}
+
+impl<'t> TilemapLayer<'t> {
+    pub fn new(tiles: &'t [Option<Tile>], width: u16, height: u16) -> Self {
+        Self {
+            tiles,
+            width,
+            height,
+            parallax: (1.0, 1.0),
+            offset: (0, 0)
+        }
+    }
+
+    pub fn get(&self, x: i32, y: i32) -> Option<Tile> {
+        if x < 0 || y < 0 || x >= self.width as i32 || y >= self.height as i32 {
+            return None;
+        }
+
+        self.tiles[y as usize * self.width as usize + x as usize]
+    }
+}
+
+/// Layers sharing a tileset, drawn first to last.
+#[derive(Copy, Clone)]
+pub struct Tilemap<'t> {
+    pub tileset: Tileset<'t>,
+    pub layers: &'t [TilemapLayer<'t>]
+}
@@ -4,14 +4,19 @@ pub mod system;
 
 use nalgebra::SVector;
 use ferret_utils::convert::float_to_string;
-use ferret_graphics::ferret_3d::pixel_data::PixelData;
-use ferret_graphics::fonts::glcd::GLCD_FONT;
+use ferret_graphics::fonts::glcd::GLCD;
 use nalgebra::Vector3;
+use nalgebra::Vector2;
 use nalgebra::Vector4;
 use nalgebra::Matrix4;
-use ferret_graphics::ferret_3d::vertex_shader_trait::VertexShaderTrait;
+use ferret_graphics::ferret_3d::instanced_vertex_shader_trait::InstancedVertexShaderTrait;
+use ferret_graphics::ferret_3d::instance_data::InstanceData;
 use nalgebra::Vector6;
-use ferret_graphics::ferret_3d::pixel_shader_trait::PixelShaderTrait;
+use ferret_graphics::ferret_3d::pixel_context::PixelContext;
+use ferret_graphics::ferret_3d::typed_shader::TypedPixelShader;
+use ferret_graphics::ferret_3d::typed_shader::TypedPixelShaderTrait;
+use ferret_graphics::ferret_3d::varyings::Varyings;
+use ferret_graphics::Varyings;
 use ferret_graphics::color::Color;
 use ferret_graphics::FerretGraphics;
 use ferret_utils::convert::integer_to_string;
@@ -22,27 +27,31 @@ use crate::system::ButtonState;
 use crate::system::ScreenTrait;
 use crate::system::TimerTrait;
 
-struct SimpleVertexShader {
-    pub model_view_matrix: Matrix4<f32>,
+#[derive(Varyings)]
+struct MeshVaryings {
+    #[position]
+    position: Vector4<f32>,
+    uv: Vector2<f32>,
+    shade: f32
 }
 
+struct SimpleVertexShader;
+
 impl SimpleVertexShader {
     pub fn new() -> Self {
-        Self {
-            model_view_matrix: Matrix4::identity()
-        }
+        Self
     }
 }
 
-impl VertexShaderTrait<6, 7> for SimpleVertexShader {
-    fn process(&self, vec: &Vector6<f32>) -> SVector<f32, 7> {
+impl InstancedVertexShaderTrait<6, 7> for SimpleVertexShader {
+    fn process(&self, instance: &InstanceData, vec: &Vector6<f32>) -> SVector<f32, 7> {
         let vec4 = Vector4::new(vec.x, vec.y, vec.z, 1.0);
-        let vec4_ = self.model_view_matrix * vec4;
-        let x = vec4_.x;
-        let y = vec4_.y;
-        let z = vec4_.z;
-        let w = vec4_.w;
-        return nalgebra::vector!(x, y, z, w, vec[3], vec[4], vec[5]);
+
+        MeshVaryings {
+            position: instance.matrix * vec4,
+            uv: Vector2::new(vec[3], vec[4]) + instance.uv_offset,
+            shade: vec[5]
+        }.to_vector()
     }
 }
 
@@ -62,12 +71,13 @@ impl<'a> SimplePixelShader<'a> {
     }
 }
 
-impl<'a> PixelShaderTrait<7> for SimplePixelShader<'a> {
+impl<'a> TypedPixelShaderTrait for SimplePixelShader<'a> {
+    type Input = MeshVaryings;
 
-    fn process(&self, v: &PixelData<7>) -> Vector3<f32> {
+    fn process(&self, v: &MeshVaryings, _context: &PixelContext) -> Vector3<f32> {
         if let Some(texture) = self.texture {
-            let x = (self.tex_wid as f32 * v.data[4]) as usize;
-            let y = (self.tex_hei as f32 * v.data[5]) as usize;
+            let x = (self.tex_wid as f32 * v.uv.x) as usize;
+            let y = (self.tex_hei as f32 * v.uv.y) as usize;
 
             let x = x.min(self.tex_wid as usize).max(0);
             let y = y.min(self.tex_hei as usize).max(0);
@@ -79,7 +89,7 @@ impl<'a> PixelShaderTrait<7> for SimplePixelShader<'a> {
 
             Vector3::new(r / 255.0, g / 255.0, b / 255.0)
         } else {
-            Vector3::new(v.data[2], v.data[3], v.data[2])
+            Vector3::new(v.position.z, v.position.w, v.position.z)
         }
     }
 }
@@ -140,7 +150,7 @@ where TController: ControllerTrait,
 
         let texture = include_bytes!("../assets/box.raw");
 
-        let mut vertex_shader = SimpleVertexShader::new();
+        let vertex_shader = SimpleVertexShader::new();
         let mut pixel_shader = SimplePixelShader::new();
 
         pixel_shader.texture = Some(texture);
@@ -171,6 +181,7 @@ where TController: ControllerTrait,
         let mut period = 0;
 
         self.graphics.set_depth_test(true);
+        self.graphics.set_interlaced_render(true);
 
         loop {
             period = self.timer.measure(|| {
@@ -236,23 +247,29 @@ where TController: ControllerTrait,
 
                 self.graphics.clear_depth_buffer(0.0);
 
-                for x in 0..2 {
+                let mut instances = [InstanceData::new(Matrix4::identity()); 2];
+
+                for (x, instance) in instances.iter_mut().enumerate() {
                     let model_matrix =
                         Matrix4::<f32>::new_translation(&Vector3::new(t_x, t_y, t_z))
                         * Matrix4::<f32>::from_euler_angles(rot_x, rot_y , 0.0)
                         * Matrix4::<f32>::new_translation(&Vector3::new(x as f32 * 0.5, x as f32 * 0.5, x as f32 * 0.01));
 
-                    let mvp_matrix = projection_matrix * (view_matrix * model_matrix);
-                    vertex_shader.model_view_matrix = mvp_matrix;
-
-                    self.graphics.draw_mesh(&vertices_0, &indexes, &vertex_shader, &pixel_shader);
+                    instance.matrix = projection_matrix * (view_matrix * model_matrix);
                 }
 
+                self.graphics.draw_mesh_instanced(
+                    &vertices_0,
+                    &indexes,
+                    &instances,
+                    &vertex_shader,
+                    &TypedPixelShader(&pixel_shader));
+
                 self.graphics.draw_string(
                     10,
                     10,
                     "This is a test\nsecond line ",
-                    &GLCD_FONT,
+                    &GLCD,
                     Color(1.0, 1.0, 1.0),
                     None);
 
@@ -265,20 +282,20 @@ where TController: ControllerTrait,
                     27,
                     &string_buffer,
                     0,
-                    &GLCD_FONT,
+                    &GLCD,
                     Color(1.0, 0.0, 0.0),
                     None
                 );
 
                 float_to_string(t_z, &mut string_buffer, 10);
-                self.graphics.draw_char_array(10, 100, &string_buffer, 0, &GLCD_FONT, Color(1.0, 1.0, 1.0), None);
+                self.graphics.draw_char_array(10, 100, &string_buffer, 0, &GLCD, Color(1.0, 1.0, 1.0), None);
 
                 if self.controller.get_button_status(ControllerButton::Start).is_down() {
                     self.graphics.draw_string(
                         10,
                         50,
                         "START",
-                        &GLCD_FONT,
+                        &GLCD,
                         Color(1.0, 1.0, 1.0),
                         None);
                 }
@@ -288,7 +305,7 @@ where TController: ControllerTrait,
                         50,
                         50,
                         "SELECT",
-                        &GLCD_FONT,
+                        &GLCD,
                         Color(1.0, 1.0, 1.0),
                         None);
                 }
@@ -298,7 +315,7 @@ where TController: ControllerTrait,
                         100,
                         50,
                         "R",
-                        &GLCD_FONT,
+                        &GLCD,
                         Color(1.0, 1.0, 1.0),
                         None);
                 }
@@ -308,7 +325,7 @@ where TController: ControllerTrait,
                         110,
                         50,
                         "L",
-                        &GLCD_FONT,
+                        &GLCD,
                         Color(1.0, 1.0, 1.0),
                         None);
                 }
@@ -318,7 +335,7 @@ where TController: ControllerTrait,
                         10,
                         60,
                         "A",
-                        &GLCD_FONT,
+                        &GLCD,
                         Color(1.0, 1.0, 1.0),
                         None);
                 }
@@ -328,7 +345,7 @@ where TController: ControllerTrait,
                         20,
                         60,
                         "B",
-                        &GLCD_FONT,
+                        &GLCD,
                         Color(1.0, 1.0, 1.0),
                         None);
                 }
@@ -338,7 +355,7 @@ where TController: ControllerTrait,
                         30,
                         60,
                         "C",
-                        &GLCD_FONT,
+                        &GLCD,
                         Color(1.0, 1.0, 1.0),
                         None);
                 }
@@ -348,7 +365,7 @@ where TController: ControllerTrait,
                         40,
                         60,
                         "D",
-                        &GLCD_FONT,
+                        &GLCD,
                         Color(1.0, 1.0, 1.0),
                         None);
                 }
@@ -358,7 +375,7 @@ where TController: ControllerTrait,
                         10,
                         70,
                         "^",
-                        &GLCD_FONT,
+                        &GLCD,
                         Color(1.0, 1.0, 1.0),
                         None);
                 }
@@ -368,7 +385,7 @@ where TController: ControllerTrait,
                         20,
                         70,
                         ">",
-                        &GLCD_FONT,
+                        &GLCD,
                         Color(1.0, 1.0, 1.0),
                         None);
                 }
@@ -378,7 +395,7 @@ where TController: ControllerTrait,
                         30,
                         70,
                         "V",
-                        &GLCD_FONT,
+                        &GLCD,
                         Color(1.0, 1.0, 1.0),
                         None);
                 }
@@ -388,7 +405,7 @@ where TController: ControllerTrait,
                         40,
                         70,
                         "<",
-                        &GLCD_FONT,
+                        &GLCD,
                         Color(1.0, 1.0, 1.0),
                         None);
                 }
@@ -2,6 +2,7 @@ pub mod system_traits;
 
 pub use system_traits::ControllerTrait;
 pub use ferret_graphics::screen_trait::ScreenTrait;
+pub use ferret_graphics::screen_trait::FrameBuffer;
 pub use system_traits::TimerTrait;
 pub use system_traits::ButtonState;
 pub use system_traits::ControllerButton;